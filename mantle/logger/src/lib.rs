@@ -1,7 +1,11 @@
 use std::{
+    cell::RefCell,
     fmt::Display,
+    future::Future,
     panic,
+    pin::Pin,
     sync::atomic::{AtomicU16, Ordering},
+    task::{Context, Poll},
 };
 
 use difference::{Changeset, Difference};
@@ -11,6 +15,100 @@ const SPACING: &str = "  ";
 
 static ACTION_COUNT: AtomicU16 = AtomicU16::new(0);
 
+thread_local! {
+    /// The logs of the [`buffered`] future which is currently being polled on this thread, if any.
+    static BUFFERED_LOGS: RefCell<Option<BufferedLogs>> = const { RefCell::new(None) };
+}
+
+/// Logs which were written by a [`buffered`] future. They are not written until they are flushed.
+#[derive(Debug)]
+pub struct BufferedLogs {
+    lines: Vec<String>,
+    action_count: u16,
+}
+
+impl BufferedLogs {
+    /// Writes the buffered logs. Any actions which the future started but did not end are
+    /// continued, so that they can be ended as if the logs had not been buffered.
+    pub fn flush(self) {
+        for line in self.lines {
+            eprintln!("{}", line);
+        }
+        ACTION_COUNT.store(self.action_count, Ordering::SeqCst);
+    }
+}
+
+struct Buffered<F: Future> {
+    future: Pin<Box<F>>,
+    logs: Option<BufferedLogs>,
+}
+
+impl<F: Future> Future for Buffered<F> {
+    type Output = (F::Output, BufferedLogs);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let previous_logs = BUFFERED_LOGS.with(|logs| logs.replace(this.logs.take()));
+        let result = this.future.as_mut().poll(cx);
+        this.logs = BUFFERED_LOGS.with(|logs| logs.replace(previous_logs));
+
+        match result {
+            Poll::Ready(output) => Poll::Ready((
+                output,
+                this.logs
+                    .take()
+                    .expect("Buffered future polled after completion."),
+            )),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Buffers everything the future logs instead of writing it, so that futures which run at the same
+/// time do not interleave their logs or their actions. The buffered logs are returned with the
+/// output of the future and should be flushed in order once it completes.
+pub fn buffered<F: Future>(future: F) -> impl Future<Output = (F::Output, BufferedLogs)> {
+    Buffered {
+        future: Box::pin(future),
+        logs: Some(BufferedLogs {
+            lines: Vec::new(),
+            action_count: get_action_count(),
+        }),
+    }
+}
+
+fn get_action_count() -> u16 {
+    BUFFERED_LOGS
+        .with(|logs| logs.borrow().as_ref().map(|logs| logs.action_count))
+        .unwrap_or_else(|| ACTION_COUNT.load(Ordering::SeqCst))
+}
+
+fn set_action_count(action_count: u16) {
+    let is_buffered = BUFFERED_LOGS.with(|logs| match logs.borrow_mut().as_mut() {
+        Some(logs) => {
+            logs.action_count = action_count;
+            true
+        }
+        None => false,
+    });
+    if !is_buffered {
+        ACTION_COUNT.store(action_count, Ordering::SeqCst);
+    }
+}
+
+fn write_line(line: String) {
+    let line = BUFFERED_LOGS.with(|logs| match logs.borrow_mut().as_mut() {
+        Some(logs) => {
+            logs.lines.push(line);
+            None
+        }
+        None => Some(line),
+    });
+    if let Some(line) = line {
+        eprintln!("{}", line);
+    }
+}
+
 fn with_prefix_and_style<S1, S2>(text: S1, prefix: S2, style: Style) -> String
 where
     S1: Display,
@@ -32,7 +130,7 @@ where
 }
 
 fn get_line_prefix() -> String {
-    format!("{SPACING}│{SPACING}").repeat(ACTION_COUNT.load(Ordering::SeqCst).into())
+    format!("{SPACING}│{SPACING}").repeat(get_action_count().into())
 }
 
 pub fn log<S>(message: S)
@@ -40,7 +138,7 @@ where
     S: Display,
 {
     let line_prefix = get_line_prefix();
-    eprintln!("{}", with_prefix(&message, line_prefix));
+    write_line(with_prefix(&message, line_prefix));
 }
 
 pub fn start_action<S>(title: S)
@@ -49,19 +147,20 @@ where
 {
     log(title);
     log("  ╷");
-    ACTION_COUNT.fetch_add(1, Ordering::SeqCst);
+    set_action_count(get_action_count() + 1);
 }
 
 fn end_action_internal<S>(message: Option<S>, results: Option<Changeset>)
 where
     S: Display,
 {
-    if ACTION_COUNT.load(Ordering::SeqCst) == 0 {
+    let action_count = get_action_count();
+    if action_count == 0 {
         panic!("Attempted to end an action that was not started.");
     }
 
     log("");
-    ACTION_COUNT.fetch_sub(1, Ordering::SeqCst);
+    set_action_count(action_count - 1);
 
     if let Some(message) = message {
         log(format!("{SPACING}╰─ {message}"));
@@ -26,6 +26,42 @@ fn get_app() -> App<'static, 'static> {
                     Arg::with_name("allow_purchases")
                         .long("allow-purchases")
                         .help("Gives Mantle permission to make purchases with Robux."))
+                .arg(
+                    Arg::with_name("target")
+                        .long("target")
+                        .short("t")
+                        .help("The ID of a resource to deploy (e.g. `place_start` or `product_fiftyGold`). Can be provided multiple times. If specified, all other resources will be left unchanged, except for any missing dependencies of the targeted resources.")
+                        .value_name("RESOURCE_ID")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1))
+                .arg(
+                    Arg::with_name("webhook")
+                        .long("webhook")
+                        .help("A URL to POST a JSON summary of the deployment to once it finishes (e.g. a Slack or Discord webhook).")
+                        .value_name("URL")
+                        .takes_value(true))
+                .arg(
+                    Arg::with_name("allow_experience_destroy")
+                        .long("allow-experience-destroy")
+                        .help("Gives Mantle permission to archive the experience if it has been removed from the state."))
+                .arg(
+                    Arg::with_name("restart_servers")
+                        .long("restart-servers")
+                        .help("Restarts the experience's servers after a deployment which publishes a place file so that players get the new version. Requires an Open Cloud API key."))
+                .arg(
+                    Arg::with_name("refresh")
+                        .long("refresh")
+                        .help("Compares each unchanged resource with its live state on Roblox, re-creating any which were deleted outside of Mantle and warning about any settings which were changed outside of Mantle."))
+                .arg(
+                    Arg::with_name("metadata")
+                        .long("metadata")
+                        .short("m")
+                        .help("A key-value pair (e.g. `commit=abc123`) to record in the state file for each resource created or updated by this deployment. Can be provided multiple times.")
+                        .value_name("KEY=VALUE")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1))
         )
          .subcommand(
             SubCommand::with_name("diff")
@@ -56,7 +92,12 @@ fn get_app() -> App<'static, 'static> {
                         .help("The format to print the diff in")
                         .value_name("FORMAT")
                         .takes_value(true)
-                        .possible_values(&["json","yaml"]))
+                        .possible_values(&["json","yaml","text"]))
+                .arg(
+                    Arg::with_name("plan")
+                        .long("plan")
+                        .help("Prints a plan of each resource's action and changed fields instead of the raw diff. Requires a format. The `text` format prints a human-readable report of the plan.")
+                        .requires("format"))
         )
         .subcommand(
             SubCommand::with_name("destroy")
@@ -175,6 +216,16 @@ pub async fn run_with(args: Vec<String>) -> i32 {
                 deploy_matches.value_of("PROJECT"),
                 deploy_matches.value_of("environment"),
                 deploy_matches.is_present("allow_purchases"),
+                deploy_matches.is_present("allow_experience_destroy"),
+                deploy_matches.is_present("restart_servers"),
+                deploy_matches.is_present("refresh"),
+                deploy_matches
+                    .values_of("target")
+                    .map(|values| values.map(|v| v.to_owned()).collect()),
+                deploy_matches.value_of("webhook"),
+                deploy_matches
+                    .values_of("metadata")
+                    .map(|values| values.map(|v| v.to_owned()).collect()),
             )
             .await
         }
@@ -184,6 +235,7 @@ pub async fn run_with(args: Vec<String>) -> i32 {
                 diff_matches.value_of("environment"),
                 diff_matches.value_of("output"),
                 diff_matches.value_of("format"),
+                diff_matches.is_present("plan"),
             )
             .await
         }
@@ -1,15 +1,27 @@
-use std::{path::PathBuf, process::Command, str};
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    process::{self, Command},
+    str,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
+use tokio::{sync::mpsc, task::JoinHandle};
 use yansi::Paint;
 
 use rbx_mantle::{
-    config::{load_project_config, TargetConfig},
+    config::{load_project_config, ExperienceTargetConfig, TargetConfig},
     project::{load_project, Project},
-    resource_graph::{EvaluateResults, ResourceGraph},
+    resource_graph::{EvaluateOptions, EvaluateResults, PlannedAction, ResourceGraph},
     roblox_resource_manager::{RobloxInputs, RobloxOutputs, RobloxResource, RobloxResourceManager},
     state::{get_desired_graph, save_state},
 };
 
+use crate::{notify::DeploymentNotifier, report::log_report};
+
 fn run_command(dir: PathBuf, command: &str) -> std::io::Result<std::process::Output> {
     if cfg!(target_os = "windows") {
         return Command::new("cmd")
@@ -76,6 +88,25 @@ fn tag_commit(
     Ok(tag_count)
 }
 
+/// Sets the returned flag when the user interrupts the process (e.g. with Ctrl+C) so that the
+/// deployment can stop before starting its next operation. A second interrupt exits immediately.
+/// The returned listener should be aborted once the deployment can no longer be cancelled.
+fn listen_for_cancellation() -> (Arc<AtomicBool>, JoinHandle<()>) {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let listener_cancelled = cancelled.clone();
+    let listener = tokio::spawn(async move {
+        while tokio::signal::ctrl_c().await.is_ok() {
+            if listener_cancelled.swap(true, Ordering::SeqCst) {
+                process::exit(130);
+            }
+            logger::log(Paint::yellow(
+                "Cancelling deployment after the current operation completes. Interrupt again to exit immediately.",
+            ));
+        }
+    });
+    (cancelled, listener)
+}
+
 fn log_target_results(
     target_config: &TargetConfig,
     graph: &ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>,
@@ -120,8 +151,76 @@ fn log_target_results(
     logger::end_action_without_message();
 }
 
-pub async fn run(project: Option<&str>, environment: Option<&str>, allow_purchases: bool) -> i32 {
+/// Archiving an experience takes it offline, so fail before anything is deleted if the deployment
+/// would archive the experience without `--allow-experience-destroy`. Only the targeted resources
+/// are checked, since the others will not be deleted.
+fn check_experience_destroy(
+    next_graph: &ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>,
+    current_graph: &ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>,
+    targets: Option<&[String]>,
+) -> Result<(), String> {
+    let targeted_resources = targets
+        .map(|targets| next_graph.get_targeted_resources(current_graph, targets))
+        .transpose()?;
+    let deletes = next_graph
+        .plan(current_graph)?
+        .into_iter()
+        .filter(|change| change.action == PlannedAction::Delete)
+        .filter(|change| match &targeted_resources {
+            Some(targeted_resources) => targeted_resources.contains(&change.resource_id),
+            None => true,
+        })
+        .collect::<Vec<_>>();
+    if !deletes
+        .iter()
+        .any(|change| change.resource_type == "experience")
+    {
+        return Ok(());
+    }
+
+    Err(format!(
+        "This deployment would archive the experience and delete the following resources:\n\t{}\nPass --allow-experience-destroy to allow the experience to be archived.",
+        deletes
+            .iter()
+            .map(|change| change.resource_id.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\t")
+    ))
+}
+
+fn parse_metadata(values: &[String]) -> Result<BTreeMap<String, String>, String> {
+    values
+        .iter()
+        .map(|value| match value.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok((key.to_owned(), value.to_owned())),
+            _ => Err(format!(
+                "Invalid metadata {}: expected the format KEY=VALUE",
+                value
+            )),
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    project: Option<&str>,
+    environment: Option<&str>,
+    allow_purchases: bool,
+    allow_experience_destroy: bool,
+    restart_servers: bool,
+    refresh: bool,
+    targets: Option<Vec<String>>,
+    webhook_url: Option<&str>,
+    metadata: Option<Vec<String>>,
+) -> i32 {
     logger::start_action("Loading project:");
+    let metadata = match metadata.map(|values| parse_metadata(&values)).transpose() {
+        Ok(v) => v,
+        Err(e) => {
+            logger::end_action(Paint::red(e));
+            return 1;
+        }
+    };
     let (project_path, config) = match load_project_config(project) {
         Ok(v) => v,
         Err(e) => {
@@ -149,13 +248,28 @@ pub async fn run(project: Option<&str>, environment: Option<&str>, allow_purchas
         }
     };
     let mut next_graph =
-        match get_desired_graph(project_path.as_path(), &target_config, &owner_config) {
+        match get_desired_graph(project_path.as_path(), &target_config, &owner_config).await {
             Ok(v) => v,
             Err(e) => {
                 logger::end_action(Paint::red(e));
                 return 1;
             }
         };
+    if let Some(metadata) = metadata {
+        next_graph.set_metadata(metadata);
+    }
+    if let Some(targets) = &targets {
+        if let Err(e) = next_graph.get_targeted_resources(&current_graph, targets) {
+            logger::end_action(Paint::red(e));
+            return 1;
+        }
+    }
+    if !allow_experience_destroy {
+        if let Err(e) = check_experience_destroy(&next_graph, &current_graph, targets.as_deref()) {
+            logger::end_action(Paint::red(e));
+            return 1;
+        }
+    }
     logger::end_action("Succeeded");
 
     logger::start_action("Deploying resources:");
@@ -167,10 +281,68 @@ pub async fn run(project: Option<&str>, environment: Option<&str>, allow_purchas
             return 1;
         }
     };
+    if let TargetConfig::Experience(ExperienceTargetConfig {
+        product_delete_behavior: Some(product_delete_behavior),
+        ..
+    }) = &target_config
+    {
+        resource_manager.set_product_delete_behavior((*product_delete_behavior).into());
+    }
+    resource_manager.set_allow_experience_destroy(allow_experience_destroy);
+
+    // The notifier also records which resources were changed for the change report
+    let notifier = DeploymentNotifier::default();
+    let observer_notifier = notifier.clone();
+    resource_manager.set_observer(move |event| observer_notifier.observe(event));
+    let planned_changes = next_graph.plan(&current_graph).ok();
 
+    // Save the state whenever resources are changed so that a deployment which is interrupted
+    // (e.g. by a CI timeout) can be resumed without repeating the completed changes. Only the most
+    // recent checkpoint is saved if they arrive faster than they can be saved.
+    let (checkpoint_sender, mut checkpoint_receiver) = mpsc::unbounded_channel();
+    let checkpoint_task = {
+        let mut state = state.clone();
+        let project_path = project_path.clone();
+        let state_config = state_config.clone();
+        let label = environment_config.label.clone();
+        tokio::spawn(async move {
+            while let Some(mut resources) = checkpoint_receiver.recv().await {
+                while let Ok(newer_resources) = checkpoint_receiver.try_recv() {
+                    resources = newer_resources;
+                }
+                state.environments.insert(label.clone(), resources);
+                if let Err(e) = save_state(&project_path, &state_config, &state).await {
+                    logger::log(Paint::yellow(format!("Unable to save checkpoint: {}", e)));
+                }
+            }
+        })
+    };
+    let checkpoint = move |resources: Vec<RobloxResource>| {
+        // The checkpoint task only stops once the sender has been dropped
+        let _ = checkpoint_sender.send(resources);
+    };
+
+    let (cancelled, cancellation_listener) = listen_for_cancellation();
     let results = next_graph
-        .evaluate(&current_graph, &mut resource_manager, allow_purchases)
+        .evaluate(
+            &current_graph,
+            &mut resource_manager,
+            EvaluateOptions {
+                allow_purchases,
+                refresh,
+                targets: targets.as_deref(),
+                cancelled: Some(cancelled.as_ref()),
+                checkpoint: Some(&checkpoint),
+            },
+        )
         .await;
+    cancellation_listener.abort();
+
+    // Wait for any pending checkpoint so that it does not overwrite the final state
+    drop(checkpoint);
+    if let Err(e) = checkpoint_task.await {
+        logger::log(Paint::yellow(format!("Unable to save checkpoint: {}", e)));
+    }
     match &results {
         Ok(results) => {
             match results {
@@ -198,6 +370,33 @@ pub async fn run(project: Option<&str>, environment: Option<&str>, allow_purchas
         }
     };
 
+    // Restart servers once all of the place files have been published rather than after each one
+    let mut restart_failed = false;
+    if restart_servers && results.is_ok() {
+        let published_place_file = notifier
+            .get_succeeded_resource_ids()
+            .iter()
+            .any(|resource_id| resource_id.starts_with("placeFile_"));
+        if published_place_file {
+            logger::start_action("Restarting servers:");
+            let result = match next_graph.get_outputs("experience_singleton") {
+                Some(RobloxOutputs::Experience(outputs)) => {
+                    resource_manager
+                        .restart_experience_servers(outputs.asset_id)
+                        .await
+                }
+                _ => Err("No experience outputs found".to_owned()),
+            };
+            match result {
+                Ok(()) => logger::end_action("Succeeded"),
+                Err(e) => {
+                    logger::end_action(Paint::red(e));
+                    restart_failed = true;
+                }
+            }
+        }
+    }
+
     if environment_config.tag_commit && results.is_ok() {
         logger::start_action("Tagging commit:");
         match tag_commit(
@@ -228,10 +427,30 @@ pub async fn run(project: Option<&str>, environment: Option<&str>, allow_purchas
     };
     logger::end_action("Succeeded");
 
+    if let Some(planned_changes) = planned_changes {
+        let succeeded_resource_ids = notifier.get_succeeded_resource_ids();
+        let applied_changes = planned_changes
+            .into_iter()
+            .filter(|change| succeeded_resource_ids.contains(&change.resource_id))
+            .collect::<Vec<_>>();
+        log_report("Change report:", &applied_changes);
+    }
+
     log_target_results(&target_config, &next_graph);
 
+    if let Some(webhook_url) = webhook_url {
+        notifier
+            .notify(
+                webhook_url,
+                &environment_config.label,
+                results.is_ok(),
+                &next_graph,
+            )
+            .await;
+    }
+
     match &results {
-        Ok(_) => 0,
-        Err(_) => 1,
+        Ok(_) if !restart_failed => 0,
+        _ => 1,
     }
 }
@@ -3,9 +3,9 @@ use std::str;
 use yansi::Paint;
 
 use rbx_mantle::{
-    config::load_project_config,
+    config::{load_project_config, ExperienceTargetConfig, TargetConfig},
     project::{load_project, Project},
-    resource_graph::{EvaluateResults, ResourceGraph},
+    resource_graph::{EvaluateOptions, EvaluateResults, ResourceGraph},
     roblox_resource_manager::RobloxResourceManager,
     state::save_state,
 };
@@ -23,6 +23,7 @@ pub async fn run(project: Option<&str>, environment: Option<&str>) -> i32 {
         current_graph,
         mut state,
         environment_config,
+        target_config,
         payment_source,
         state_config,
         ..
@@ -48,10 +49,23 @@ pub async fn run(project: Option<&str>, environment: Option<&str>) -> i32 {
             return 1;
         }
     };
+    if let TargetConfig::Experience(ExperienceTargetConfig {
+        product_delete_behavior: Some(product_delete_behavior),
+        ..
+    }) = &target_config
+    {
+        resource_manager.set_product_delete_behavior((*product_delete_behavior).into());
+    }
+    // Destroying the environment archives its experience by design.
+    resource_manager.set_allow_experience_destroy(true);
 
     let mut next_graph = ResourceGraph::new(&Vec::new());
     let results = next_graph
-        .evaluate(&current_graph, &mut resource_manager, false)
+        .evaluate(
+            &current_graph,
+            &mut resource_manager,
+            EvaluateOptions::default(),
+        )
         .await;
     match &results {
         Ok(results) => {
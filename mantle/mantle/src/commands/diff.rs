@@ -10,6 +10,8 @@ use rbx_mantle::{
     state::get_desired_graph,
 };
 
+use crate::report::{log_report, render_report};
+
 fn get_changeset(previous_hash: &str, new_hash: &str) -> Changeset {
     Changeset::new(previous_hash, new_hash, "\n")
 }
@@ -62,6 +64,7 @@ pub async fn run(
     environment: Option<&str>,
     output: Option<&str>,
     format: Option<&str>,
+    plan: bool,
 ) -> i32 {
     logger::start_action("Loading project:");
     let (project_path, config) = match load_project_config(project) {
@@ -88,7 +91,7 @@ pub async fn run(
         }
     };
     let mut next_graph =
-        match get_desired_graph(project_path.as_path(), &target_config, &owner_config) {
+        match get_desired_graph(project_path.as_path(), &target_config, &owner_config).await {
             Ok(v) => v,
             Err(e) => {
                 logger::end_action(Paint::red(e));
@@ -99,19 +102,43 @@ pub async fn run(
 
     logger::start_action("Diffing resource graphs:");
 
+    let planned_changes = if plan || format == Some("text") {
+        match next_graph.plan(&current_graph) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                logger::end_action(Paint::red(e));
+                return 1;
+            }
+        }
+    } else {
+        None
+    };
+
     let diff = next_graph.diff(&current_graph);
 
     match diff {
         Ok(diff) => {
-            let outputs_string = format.map(|format| match format {
-                "json" => serde_json::to_string_pretty(&diff)
+            let outputs_string = format.map(|format| match (format, &planned_changes) {
+                ("json", Some(planned_changes)) => serde_json::to_string_pretty(planned_changes)
+                    .map(|x| x + "\n")
+                    .map_err(|e| e.to_string()),
+                ("json", None) => serde_json::to_string_pretty(&diff)
                     .map(|x| x + "\n")
                     .map_err(|e| e.to_string()),
-                "yaml" => serde_yaml::to_string(&diff).map_err(|e| e.to_string()),
+                ("yaml", Some(planned_changes)) => {
+                    serde_yaml::to_string(planned_changes).map_err(|e| e.to_string())
+                }
+                ("yaml", None) => serde_yaml::to_string(&diff).map_err(|e| e.to_string()),
+                ("text", Some(planned_changes)) => {
+                    Ok(render_report(planned_changes, false).join("\n") + "\n")
+                }
                 _ => Err(format!("Unknown format: {}", format)),
             });
 
-            print_diff(diff);
+            match &planned_changes {
+                Some(planned_changes) if plan => log_report("Planned changes:", planned_changes),
+                _ => print_diff(diff),
+            }
             logger::end_action("Succeeded");
 
             if let Some(outputs_string) = outputs_string {
@@ -3,6 +3,8 @@ use log::info;
 
 mod cli;
 mod commands;
+mod notify;
+mod report;
 
 #[tokio::main]
 async fn main() {
@@ -0,0 +1,189 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use serde::Serialize;
+use yansi::Paint;
+
+use rbx_mantle::{
+    resource_graph::{ResourceEvent, ResourceEventKind, ResourceGraph, ResourceOperation},
+    roblox_resource_manager::{RobloxInputs, RobloxOutputs, RobloxResource},
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceSummary {
+    resource_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    asset_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FailureSummary {
+    resource_id: String,
+    error: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeploymentSummary {
+    /// A human-readable summary, used as the message body by Slack (`text`) and Discord (`content`).
+    text: String,
+    content: String,
+    success: bool,
+    environment: String,
+    created: Vec<ResourceSummary>,
+    updated: Vec<ResourceSummary>,
+    deleted: Vec<ResourceSummary>,
+    failed: Vec<FailureSummary>,
+}
+
+/// Collects the results of each resource operation from the resource manager's progress events so
+/// that they can be reported and sent to a webhook once the deployment finishes.
+#[derive(Clone, Default)]
+pub struct DeploymentNotifier {
+    events: Arc<Mutex<Vec<ResourceEvent>>>,
+}
+
+impl DeploymentNotifier {
+    pub fn observe(&self, event: ResourceEvent) {
+        if !matches!(event.kind, ResourceEventKind::Started) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    /// The IDs of the resources which were created, updated, or deleted successfully.
+    pub fn get_succeeded_resource_ids(&self) -> HashSet<String> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| matches!(event.kind, ResourceEventKind::Succeeded))
+            .map(|event| event.resource_id.clone())
+            .collect()
+    }
+
+    fn summarize(
+        &self,
+        environment: &str,
+        success: bool,
+        graph: &ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>,
+    ) -> DeploymentSummary {
+        let mut created = Vec::new();
+        let mut updated = Vec::new();
+        let mut deleted = Vec::new();
+        let mut failed = Vec::new();
+
+        for event in self.events.lock().unwrap().iter() {
+            match (&event.kind, event.operation) {
+                (ResourceEventKind::Failed(error), _) => failed.push(FailureSummary {
+                    resource_id: event.resource_id.clone(),
+                    error: error.clone(),
+                }),
+                (_, ResourceOperation::Create) => {
+                    created.push(get_resource_summary(&event.resource_id, graph))
+                }
+                (_, ResourceOperation::Update) => {
+                    updated.push(get_resource_summary(&event.resource_id, graph))
+                }
+                (_, ResourceOperation::Delete) => deleted.push(ResourceSummary {
+                    resource_id: event.resource_id.clone(),
+                    asset_id: None,
+                    url: None,
+                }),
+            }
+        }
+
+        let text = format!(
+            "Mantle deployment to {} {} with {} create(s), {} update(s), {} delete(s), and {} failure(s)",
+            environment,
+            if success { "succeeded" } else { "failed" },
+            created.len(),
+            updated.len(),
+            deleted.len(),
+            failed.len()
+        );
+
+        DeploymentSummary {
+            content: text.clone(),
+            text,
+            success,
+            environment: environment.to_owned(),
+            created,
+            updated,
+            deleted,
+            failed,
+        }
+    }
+
+    /// Sends a summary of the deployment to the webhook. Failures are logged as warnings rather
+    /// than returned so that they do not fail the deployment.
+    pub async fn notify(
+        &self,
+        webhook_url: &str,
+        environment: &str,
+        success: bool,
+        graph: &ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>,
+    ) {
+        logger::start_action("Sending deployment notification:");
+        let summary = self.summarize(environment, success, graph);
+        let result = reqwest::Client::new()
+            .post(webhook_url)
+            .json(&summary)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => logger::end_action("Succeeded"),
+            Err(e) => logger::end_action(Paint::yellow(format!(
+                "Unable to send deployment notification: {}",
+                e
+            ))),
+        }
+    }
+}
+
+fn get_resource_summary(
+    resource_id: &str,
+    graph: &ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>,
+) -> ResourceSummary {
+    let (asset_id, url) = match graph.get_outputs(resource_id) {
+        Some(RobloxOutputs::Experience(outputs)) => (
+            Some(outputs.asset_id),
+            Some(format!(
+                "https://www.roblox.com/games/{}",
+                outputs.start_place_id
+            )),
+        ),
+        Some(RobloxOutputs::Place(outputs)) => (
+            Some(outputs.asset_id),
+            Some(format!("https://www.roblox.com/games/{}", outputs.asset_id)),
+        ),
+        Some(RobloxOutputs::Pass(outputs)) => (
+            Some(outputs.asset_id),
+            Some(format!(
+                "https://www.roblox.com/game-pass/{}",
+                outputs.asset_id
+            )),
+        ),
+        Some(RobloxOutputs::Badge(outputs)) => (
+            Some(outputs.asset_id),
+            Some(format!(
+                "https://www.roblox.com/badges/{}",
+                outputs.asset_id
+            )),
+        ),
+        Some(RobloxOutputs::Product(outputs)) => (Some(outputs.asset_id), None),
+        _ => (None, None),
+    };
+
+    ResourceSummary {
+        resource_id: resource_id.to_owned(),
+        asset_id,
+        url,
+    }
+}
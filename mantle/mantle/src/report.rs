@@ -0,0 +1,108 @@
+use yansi::{Color, Paint};
+
+use rbx_mantle::resource_graph::{FieldChange, PlannedAction, PlannedChange};
+
+/// Values longer than this are truncated so that each field fits on one line.
+const MAX_VALUE_LENGTH: usize = 80;
+
+fn format_value(value: &Option<String>) -> String {
+    match value {
+        Some(value) if value.chars().count() > MAX_VALUE_LENGTH => {
+            format!(
+                "{}…",
+                value.chars().take(MAX_VALUE_LENGTH).collect::<String>()
+            )
+        }
+        Some(value) => value.clone(),
+        None => "(not set)".to_owned(),
+    }
+}
+
+fn format_field_change(action: PlannedAction, change: &FieldChange) -> (String, String) {
+    // File hashes are not meaningful to read, so only report whether the file will be uploaded
+    if change.field == "fileHash" {
+        let description = match (action, &change.current_value) {
+            (_, None) => "removed",
+            (PlannedAction::Create, _) => "uploaded",
+            _ => "re-uploaded",
+        };
+        return ("file".to_owned(), description.to_owned());
+    }
+
+    let description = match action {
+        PlannedAction::Create => format_value(&change.current_value),
+        _ => format!(
+            "{} → {}",
+            format_value(&change.previous_value),
+            format_value(&change.current_value)
+        ),
+    };
+    (change.field.clone(), description)
+}
+
+fn paint(text: &str, color: Color, colored: bool) -> String {
+    if colored {
+        Paint::new(text).fg(color).to_string()
+    } else {
+        text.to_owned()
+    }
+}
+
+/// Renders changes (from a plan or a deployment) as a human-readable report with a line for each
+/// resource, followed by a table of its changed fields with their previous and current values.
+/// Deleted resources only list their ID.
+pub fn render_report(changes: &[PlannedChange], colored: bool) -> Vec<String> {
+    let mut lines = Vec::new();
+    for change in changes {
+        let (symbol, action) = match change.action {
+            PlannedAction::Create => (paint("+", Color::Green, colored), "create"),
+            PlannedAction::Update => (paint("~", Color::Yellow, colored), "update"),
+            PlannedAction::Delete => (paint("-", Color::Red, colored), "delete"),
+        };
+        lines.push(format!("{} {} {}", symbol, action, change.resource_id));
+
+        if change.action == PlannedAction::Delete {
+            continue;
+        }
+
+        let rows: Vec<(String, String)> = change
+            .field_changes
+            .iter()
+            .map(|field_change| format_field_change(change.action, field_change))
+            .collect();
+        let field_width = rows
+            .iter()
+            .map(|(field, _)| field.chars().count())
+            .max()
+            .unwrap_or_default();
+        for (field, description) in rows {
+            lines.push(format!(
+                "    {:width$}  {}",
+                field,
+                description,
+                width = field_width
+            ));
+        }
+
+        if !change.changed_dependencies.is_empty() {
+            lines.push(format!(
+                "    dependencies changed: {}",
+                change.changed_dependencies.join(", ")
+            ));
+        }
+    }
+
+    lines
+}
+
+/// Logs the report of the changes (see [`render_report`]) as an action.
+pub fn log_report(title: &str, changes: &[PlannedChange]) {
+    logger::start_action(title);
+    if changes.is_empty() {
+        logger::log("No changes");
+    }
+    for line in render_report(changes, true) {
+        logger::log(line);
+    }
+    logger::end_action_without_message();
+}
@@ -3,12 +3,7 @@ mod models;
 use reqwest::header;
 use serde_json::json;
 
-use crate::{
-    errors::RobloxApiResult,
-    helpers::{handle, handle_as_json},
-    models::AssetId,
-    RobloxApi,
-};
+use crate::{errors::RobloxApiResult, models::AssetId, RobloxApi};
 
 use self::models::{GetAssetAliasResponse, ListAssetAliasesResponse};
 
@@ -20,7 +15,7 @@ impl RobloxApi {
         name: String,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://develop.roblox.com/v1/universes/{}/aliases",
                 experience_id
@@ -31,7 +26,7 @@ impl RobloxApi {
                 "targetId": asset_id,
             }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -44,7 +39,7 @@ impl RobloxApi {
         name: String,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/content-aliases-api/v1/universes/update-alias")
             .query(&[
                 ("universeId", experience_id.to_string().as_str()),
@@ -54,7 +49,7 @@ impl RobloxApi {
                 ("targetId", asset_id.to_string().as_str()),
             ]);
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -65,12 +60,12 @@ impl RobloxApi {
         name: String,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/content-aliases-api/v1/universes/delete-alias")
             .header(header::CONTENT_LENGTH, 0)
             .query(&[("universeId", &experience_id.to_string()), ("name", &name)]);
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -81,14 +76,14 @@ impl RobloxApi {
         page: u32,
     ) -> RobloxApiResult<ListAssetAliasesResponse> {
         let req = self
-            .client
+            .client()
             .get("https://apis.roblox.com/content-aliases-api/v1/universes/get-aliases")
             .query(&[
                 ("universeId", &experience_id.to_string()),
                 ("page", &page.to_string()),
             ]);
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn get_all_asset_aliases(
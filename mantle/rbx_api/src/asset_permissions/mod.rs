@@ -1,4 +1,4 @@
-use crate::{errors::RobloxApiResult, helpers::handle, models::AssetId, RobloxApi};
+use crate::{errors::RobloxApiResult, models::AssetId, RobloxApi};
 
 use self::models::GrantAssetPermissionsRequest;
 
@@ -14,14 +14,14 @@ impl RobloxApi {
         R: Into<GrantAssetPermissionsRequest>,
     {
         let req = self
-            .client
+            .client()
             .patch(format!(
                 "https://apis.roblox.com/asset-permissions-api/v1/assets/{}/permissions",
                 asset_id
             ))
             .json(&request.into());
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -7,7 +7,6 @@ use serde_json::json;
 
 use crate::{
     errors::{RobloxApiError, RobloxApiResult},
-    helpers::{handle, handle_as_json, handle_as_json_with_status},
     models::{AssetId, AssetTypeId, CreatorType},
     RobloxApi,
 };
@@ -30,7 +29,7 @@ impl RobloxApi {
         );
 
         let mut req = self
-            .client
+            .client()
             .post("https://data.roblox.com/data/upload/json")
             .header(reqwest::header::CONTENT_TYPE, "*/*")
             .body(data)
@@ -43,7 +42,7 @@ impl RobloxApi {
             req = req.query(&[("groupId", &group_id.to_string())]);
         }
 
-        handle_as_json_with_status(req).await
+        self.handle_as_json_with_status(req).await
     }
 
     pub async fn get_create_asset_quota(
@@ -51,7 +50,7 @@ impl RobloxApi {
         asset_type: AssetTypeId,
     ) -> RobloxApiResult<CreateAssetQuota> {
         let req = self
-            .client
+            .client()
             .get("https://publish.roblox.com/v1/asset-quotas")
             .query(&[
                 // TODO: Understand what this parameter does
@@ -60,7 +59,9 @@ impl RobloxApi {
             ]);
 
         // TODO: Understand how to interpret multiple quota objects (rather than just using the first one)
-        (handle_as_json::<CreateAssetQuotasResponse>(req).await?)
+        (self
+            .handle_as_json::<CreateAssetQuotasResponse>(req)
+            .await?)
             .quotas
             .first()
             .cloned()
@@ -81,7 +82,7 @@ impl RobloxApi {
         );
 
         let req = self
-            .client
+            .client()
             .post("https://publish.roblox.com/v1/audio")
             .json(&json!({
                 "name": file_name,
@@ -90,19 +91,19 @@ impl RobloxApi {
                 "paymentSource": payment_source
             }));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn archive_asset(&self, asset_id: AssetId) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://develop.roblox.com/v1/assets/{}/archive",
                 asset_id
             ))
             .header(header::CONTENT_LENGTH, 0);
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -7,12 +7,12 @@ use serde_json::json;
 
 use crate::{
     errors::RobloxApiResult,
-    helpers::{get_file_part, handle, handle_as_json},
+    helpers::get_image_part,
     models::{AssetId, CreatorType, UploadImageResponse},
     RobloxApi,
 };
 
-use self::models::{CreateBadgeResponse, ListBadgeResponse, ListBadgesResponse};
+use self::models::{CreateBadgeResponse, GetBadgeResponse, ListBadgeResponse, ListBadgesResponse};
 
 impl RobloxApi {
     pub async fn create_badge(
@@ -25,21 +25,21 @@ impl RobloxApi {
         expected_cost: u32,
     ) -> RobloxApiResult<CreateBadgeResponse> {
         let req = self
-            .client
+            .client()
             .post(&format!(
                 "https://badges.roblox.com/v1/universes/{}/badges",
                 experience_id
             ))
             .multipart(
                 Form::new()
-                    .part("request.files", get_file_part(icon_file_path).await?)
+                    .part("request.files", get_image_part(icon_file_path).await?)
                     .text("request.name", name)
                     .text("request.description", description)
                     .text("request.paymentSourceType", payment_source.to_string())
                     .text("request.expectedCost", expected_cost.to_string()),
             );
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn update_badge(
@@ -50,7 +50,7 @@ impl RobloxApi {
         enabled: bool,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .patch(format!("https://badges.roblox.com/v1/badges/{}", badge_id))
             .json(&json!({
                 "name": name,
@@ -58,7 +58,7 @@ impl RobloxApi {
                 "enabled": enabled,
             }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -67,12 +67,20 @@ impl RobloxApi {
         &self,
         experience_id: AssetId,
     ) -> RobloxApiResult<i32> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://badges.roblox.com/v1/universes/{}/free-badges-quota",
             experience_id
         ));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
+    }
+
+    pub async fn get_badge(&self, badge_id: AssetId) -> RobloxApiResult<GetBadgeResponse> {
+        let req = self
+            .client()
+            .get(format!("https://badges.roblox.com/v1/badges/{}", badge_id));
+
+        self.handle_as_json(req).await
     }
 
     pub async fn list_badges(
@@ -80,7 +88,7 @@ impl RobloxApi {
         experience_id: AssetId,
         page_cursor: Option<String>,
     ) -> RobloxApiResult<ListBadgesResponse> {
-        let mut req = self.client.get(format!(
+        let mut req = self.client().get(format!(
             "https://badges.roblox.com/v1/universes/{}/badges",
             experience_id
         ));
@@ -88,7 +96,7 @@ impl RobloxApi {
             req = req.query(&[("cursor", &page_cursor)]);
         }
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn get_all_badges(
@@ -118,13 +126,13 @@ impl RobloxApi {
         icon_file: PathBuf,
     ) -> RobloxApiResult<UploadImageResponse> {
         let req = self
-            .client
+            .client()
             .post(&format!(
                 "https://publish.roblox.com/v1/badges/{}/icon",
                 badge_id
             ))
-            .multipart(Form::new().part("request.files", get_file_part(icon_file).await?));
+            .multipart(Form::new().part("request.files", get_image_part(icon_file).await?));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 }
@@ -9,6 +9,20 @@ pub struct CreateBadgeResponse {
     pub icon_image_id: AssetId,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBadgeResponse {
+    pub id: AssetId,
+    pub icon_image_id: AssetId,
+    pub awarding_universe: Option<GetBadgeAwardingUniverseResponse>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBadgeAwardingUniverseResponse {
+    pub id: AssetId,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListBadgesResponse {
@@ -1,14 +1,15 @@
 pub mod models;
 
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use reqwest::{header, multipart::Form};
 use serde_json::json;
 
 use crate::{
-    errors::RobloxApiResult,
-    helpers::{get_file_part, handle, handle_as_json},
+    errors::{RobloxApiError, RobloxApiResult},
+    helpers::get_image_part,
     models::AssetId,
+    retry::with_retry,
     RobloxApi,
 };
 
@@ -24,14 +25,14 @@ impl RobloxApi {
         icon_file: PathBuf,
     ) -> RobloxApiResult<CreateDeveloperProductIconResponse> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://apis.roblox.com/developer-products/v1/developer-products/{}/image",
                 developer_product_id
             ))
-            .multipart(Form::new().part("imageFile", get_file_part(icon_file).await?));
+            .multipart(Form::new().part("imageFile", get_image_part(icon_file).await?));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn create_developer_product(
@@ -42,7 +43,7 @@ impl RobloxApi {
         description: String,
     ) -> RobloxApiResult<CreateDeveloperProductResponse> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://apis.roblox.com/developer-products/v1/universes/{}/developerproducts",
                 experience_id
@@ -54,7 +55,7 @@ impl RobloxApi {
                 ("description", &description),
             ]);
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn list_developer_products(
@@ -63,14 +64,14 @@ impl RobloxApi {
         page: u32,
     ) -> RobloxApiResult<ListDeveloperProductsResponse> {
         let req = self
-            .client
+            .client()
             .get("https://apis.roblox.com/developer-products/v1/developer-products/list")
             .query(&[
                 ("universeId", &experience_id.to_string()),
                 ("page", &page.to_string()),
             ]);
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn get_all_developer_products(
@@ -94,16 +95,48 @@ impl RobloxApi {
         Ok(all_products)
     }
 
+    /// Finds a developer product in the experience's list of developer products, following the
+    /// list's pages until it is found.
+    pub async fn find_experience_developer_product_by_id(
+        &self,
+        experience_id: AssetId,
+        developer_product_id: AssetId,
+    ) -> RobloxApiResult<ListDeveloperProductResponseItem> {
+        let mut page: u32 = 1;
+        loop {
+            let res = self.list_developer_products(experience_id, page).await?;
+            if let Some(product) = res
+                .developer_products
+                .into_iter()
+                .find(|p| p.developer_product_id == developer_product_id)
+            {
+                return Ok(product);
+            }
+
+            if res.final_page {
+                return Err(RobloxApiError::DeveloperProductNotFound {
+                    developer_product_id,
+                    pages: page,
+                });
+            }
+
+            page += 1;
+        }
+    }
+
     pub async fn get_developer_product(
         &self,
         developer_product_id: AssetId,
     ) -> RobloxApiResult<GetDeveloperProductResponse> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://apis.roblox.com/developer-products/v1/developer-products/{}",
             developer_product_id
         ));
 
-        handle_as_json(req).await
+        with_retry(&self.retry_policy, &self.retry_budget, req, |req| {
+            self.handle_as_json(req)
+        })
+        .await
     }
 
     pub async fn update_developer_product(
@@ -113,21 +146,154 @@ impl RobloxApi {
         name: String,
         price: u32,
         description: String,
+        regional_prices: Option<&BTreeMap<String, u32>>,
+    ) -> RobloxApiResult<()> {
+        let mut body = json!({
+            "Name": name,
+            "PriceInRobux": price,
+            "Description": description,
+        });
+        // Regional prices are only sent when they are configured so that prices set elsewhere are
+        // left unchanged.
+        if let Some(regional_prices) = regional_prices {
+            body["RegionalPricesInRobux"] = json!(regional_prices);
+        }
+
+        let req = self
+            .client()
+            .post(format!(
+                "https://apis.roblox.com/developer-products/v1/universes/{}/developerproducts/{}/update",
+                experience_id, product_id
+            ))
+            .json(&body);
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    /// Sets a developer product's icon to an existing image asset instead of uploading a new one.
+    pub async fn set_developer_product_icon(
+        &self,
+        experience_id: AssetId,
+        product_id: AssetId,
+        icon_asset_id: AssetId,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://apis.roblox.com/developer-products/v1/universes/{}/developerproducts/{}/update",
                 experience_id, product_id
             ))
             .json(&json!({
-                "Name": name,
-                "PriceInRobux": price,
-                "Description": description,
+                "IconImageAssetId": icon_asset_id,
             }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
+
+    /// Sets whether a developer product is for sale. Inactive products cannot be purchased but
+    /// keep their name, description, and price.
+    pub async fn set_developer_product_active(
+        &self,
+        experience_id: AssetId,
+        product_id: AssetId,
+        is_active: bool,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .post(format!(
+                "https://apis.roblox.com/developer-products/v1/universes/{}/developerproducts/{}/update",
+                experience_id, product_id
+            ))
+            .json(&json!({
+                "IsForSale": is_active,
+            }));
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::http_client::tests::MockHttpClient;
+
+    fn developer_products_page(
+        developer_product_ids: &[AssetId],
+        final_page: bool,
+    ) -> serde_json::Value {
+        json!({
+            "DeveloperProducts": developer_product_ids
+                .iter()
+                .map(|developer_product_id| json!({
+                    "ProductId": developer_product_id + 1000,
+                    "DeveloperProductId": developer_product_id,
+                    "Name": format!("Product {}", developer_product_id),
+                    "Description": null,
+                    "IconImageAssetId": null,
+                    "PriceInRobux": 10,
+                }))
+                .collect::<Vec<_>>(),
+            "FinalPage": final_page,
+        })
+    }
+
+    #[tokio::test]
+    async fn find_experience_developer_product_by_id_follows_pages() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, developer_products_page(&[1, 2], false)),
+            (StatusCode::OK, developer_products_page(&[3, 4], false)),
+            (StatusCode::OK, developer_products_page(&[5, 6], true)),
+        ]);
+
+        let product = http_client
+            .api()
+            .find_experience_developer_product_by_id(100, 5)
+            .await
+            .unwrap();
+
+        assert_eq!(product.developer_product_id, 5);
+        assert_eq!(product.product_id, 1005);
+        let pages = http_client
+            .requests()
+            .iter()
+            .map(|(_, url)| {
+                url.query_pairs()
+                    .find(|(key, _)| key == "page")
+                    .map(|(_, value)| value.into_owned())
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(pages, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn find_experience_developer_product_by_id_stops_at_the_final_page() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, developer_products_page(&[1, 2], false)),
+            (StatusCode::OK, developer_products_page(&[3, 4], false)),
+            (StatusCode::OK, developer_products_page(&[5, 6], true)),
+        ]);
+
+        let result = http_client
+            .api()
+            .find_experience_developer_product_by_id(100, 7)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RobloxApiError::DeveloperProductNotFound {
+                developer_product_id: 7,
+                pages: 3,
+            })
+        ));
+        assert_eq!(http_client.requests().len(), 3);
+    }
 }
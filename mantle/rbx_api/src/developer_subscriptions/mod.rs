@@ -0,0 +1,113 @@
+pub mod models;
+
+use std::path::PathBuf;
+
+use reqwest::{multipart::Form, StatusCode};
+
+use crate::{
+    errors::{RobloxApiError, RobloxApiResult},
+    helpers::get_image_part,
+    models::AssetId,
+    RobloxApi,
+};
+
+use self::models::{
+    CreateDeveloperSubscriptionResponse, DeveloperSubscriptionPeriod,
+    GetDeveloperSubscriptionResponse,
+};
+
+impl RobloxApi {
+    pub async fn create_developer_subscription(
+        &self,
+        experience_id: AssetId,
+        name: String,
+        description: String,
+        price: u32,
+        period: DeveloperSubscriptionPeriod,
+        icon_file: PathBuf,
+    ) -> RobloxApiResult<CreateDeveloperSubscriptionResponse> {
+        let req = self
+            .client()
+            .post(format!(
+                "https://apis.roblox.com/developer-subscriptions/v1/universes/{}/subscriptions",
+                experience_id
+            ))
+            .multipart(
+                Form::new()
+                    .text("name", name)
+                    .text("description", description)
+                    .text("priceInRobux", price.to_string())
+                    .text("period", period.as_str())
+                    .part("imageFile", get_image_part(icon_file).await?),
+            );
+
+        self.handle_as_json(req).await
+    }
+
+    pub async fn get_developer_subscription(
+        &self,
+        experience_id: AssetId,
+        subscription_id: AssetId,
+    ) -> RobloxApiResult<GetDeveloperSubscriptionResponse> {
+        let req = self.client().get(format!(
+            "https://apis.roblox.com/developer-subscriptions/v1/universes/{}/subscriptions/{}",
+            experience_id, subscription_id
+        ));
+
+        self.handle_as_json(req).await
+    }
+
+    pub async fn update_developer_subscription(
+        &self,
+        experience_id: AssetId,
+        subscription_id: AssetId,
+        name: String,
+        description: String,
+        price: u32,
+        icon_file: Option<PathBuf>,
+    ) -> RobloxApiResult<GetDeveloperSubscriptionResponse> {
+        let mut form = Form::new()
+            .text("name", name)
+            .text("description", description)
+            .text("priceInRobux", price.to_string());
+        if let Some(icon_file) = icon_file {
+            form = form.part("imageFile", get_image_part(icon_file).await?);
+        }
+
+        let req = self
+            .client()
+            .patch(format!(
+                "https://apis.roblox.com/developer-subscriptions/v1/universes/{}/subscriptions/{}",
+                experience_id, subscription_id
+            ))
+            .multipart(form);
+
+        self.handle(req).await?;
+
+        self.get_developer_subscription(experience_id, subscription_id)
+            .await
+    }
+
+    pub async fn deactivate_developer_subscription(
+        &self,
+        experience_id: AssetId,
+        subscription_id: AssetId,
+    ) -> RobloxApiResult<()> {
+        let req = self.client().post(format!(
+            "https://apis.roblox.com/developer-subscriptions/v1/universes/{}/subscriptions/{}/deactivate",
+            experience_id, subscription_id
+        ));
+
+        match self.handle(req).await {
+            Ok(_) => Ok(()),
+            Err(RobloxApiError::Roblox {
+                status_code: StatusCode::CONFLICT,
+                reason,
+            }) => Err(RobloxApiError::DeveloperSubscriptionHasActiveSubscribers {
+                subscription_id,
+                reason,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+}
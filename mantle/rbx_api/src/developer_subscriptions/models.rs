@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::AssetId;
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "PascalCase")]
+pub enum DeveloperSubscriptionPeriod {
+    Monthly,
+}
+
+impl DeveloperSubscriptionPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeveloperSubscriptionPeriod::Monthly => "Monthly",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDeveloperSubscriptionResponse {
+    pub id: AssetId,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDeveloperSubscriptionResponse {
+    pub id: AssetId,
+    pub name: String,
+    pub description: String,
+    pub image_asset_id: Option<AssetId>,
+    pub price_in_robux: u32,
+    pub period: DeveloperSubscriptionPeriod,
+}
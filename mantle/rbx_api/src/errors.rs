@@ -2,7 +2,7 @@ use reqwest::StatusCode;
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::models::AssetTypeId;
+use crate::models::{AssetId, AssetTypeId};
 
 // TODO: Improve some of these error messages.
 #[derive(Error, Debug)]
@@ -13,6 +13,9 @@ pub enum RobloxApiError {
     #[error("Authorization has been denied for this request. Check your ROBLOSECURITY cookie.")]
     Authorization,
 
+    #[error("Your ROBLOSECURITY cookie expired or was revoked. Log in to Roblox Studio again (or update the ROBLOSECURITY environment variable) and re-run the command. Operations which completed before this one have been saved to the state.")]
+    AuthorizationExpired,
+
     #[error("Roblox error ({status_code}): {reason}")]
     Roblox {
         status_code: StatusCode,
@@ -37,6 +40,12 @@ pub enum RobloxApiError {
     #[error("Invalid file extension for path {0}.")]
     InvalidFileExtension(String),
 
+    #[error("Unsupported image format for path {0}. Images must be PNG or JPEG files.")]
+    UnsupportedImageFormat(String),
+
+    #[error("Invalid place file extension for path {0}. Place files must be .rbxl (binary) or .rbxlx (XML) files.")]
+    InvalidPlaceFileExtension(String),
+
     #[error("Failed to read utf8 data: {0}")]
     InvalidUtf8(#[from] std::string::FromUtf8Error),
 
@@ -54,6 +63,27 @@ pub enum RobloxApiError {
 
     #[error("Place file size may be too large.")]
     RbxlPlaceFileSizeMayBeTooLarge,
+
+    #[error("Developer product {developer_product_id} not found after {pages} pages.")]
+    DeveloperProductNotFound {
+        developer_product_id: AssetId,
+        pages: u32,
+    },
+
+    #[error("Developer subscription {subscription_id} could not be deactivated because it has active subscribers: {reason}")]
+    DeveloperSubscriptionHasActiveSubscribers {
+        subscription_id: AssetId,
+        reason: String,
+    },
+
+    #[error("Roblox requires a challenge ({challenge_type}) to be completed for this request. Accounts which require multi-factor verification cannot be used headlessly; complete the challenge and provide it with the ROBLOX_CHALLENGE_ID, ROBLOX_CHALLENGE_TYPE and ROBLOX_CHALLENGE_METADATA environment variables.")]
+    ChallengeRequired { challenge_type: String },
+
+    #[error("Giving up after {retries} retries during this run because Roblox appears to be unavailable. Operations which completed before this one have been saved to the state. Last error: {error}")]
+    RetryBudgetExhausted {
+        retries: u32,
+        error: Box<RobloxApiError>,
+    },
 }
 
 // Temporary to make the new errors backwards compatible with the String errors throughout the project.
@@ -5,20 +5,46 @@ use serde_json::json;
 
 use crate::{
     errors::RobloxApiResult,
-    helpers::{handle, handle_as_json},
     models::AssetId,
+    retry::{with_create_reconciliation, with_retry},
     RobloxApi,
 };
 
-use self::models::{CreateExperienceResponse, ExperienceConfigurationModel, GetExperienceResponse};
+use self::models::{
+    CreateExperienceResponse, ExperienceCollaboratorPermission, ExperienceConfigurationModel,
+    ExperienceLocalizedMetadata, GetExperienceResponse,
+    ListExperienceCollaboratorPermissionsResponse, ListExperienceLocalizedMetadataResponse,
+    ListExperienceResponse, ListExperiencesResponse, PrivateServerSettingsModel,
+    UniverseAvatarModel,
+};
+
+/// The number of recently created experiences which are listed when checking whether an experience
+/// was created by a failed request.
+const LIST_RECENT_EXPERIENCES_LIMIT: u32 = 50;
 
 impl RobloxApi {
+    /// Creates a new experience owned by the group, or by the authenticated user if no group is
+    /// given. If the request fails with a transient error, the owner's most recently created
+    /// experiences are checked so that an experience which was created by the failed request is
+    /// returned rather than creating a second one.
     pub async fn create_experience(
         &self,
         group_id: Option<AssetId>,
+    ) -> RobloxApiResult<CreateExperienceResponse> {
+        with_create_reconciliation(
+            self.create_experience_once(group_id),
+            || self.list_recent_experiences(group_id),
+            |experience| experience.universe_id,
+        )
+        .await
+    }
+
+    async fn create_experience_once(
+        &self,
+        group_id: Option<AssetId>,
     ) -> RobloxApiResult<CreateExperienceResponse> {
         let mut req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/universes/v1/universes/create")
             .json(&json!({
                 "templatePlaceId": 95206881,
@@ -28,31 +54,69 @@ impl RobloxApi {
             req = req.query(&[("groupId", group_id.to_string())]);
         }
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
+    }
+
+    /// Lists the most recently created experiences owned by the group, or by the authenticated user
+    /// if no group is given.
+    pub async fn list_recent_experiences(
+        &self,
+        group_id: Option<AssetId>,
+    ) -> RobloxApiResult<Vec<CreateExperienceResponse>> {
+        let url = match group_id {
+            Some(group_id) => format!(
+                "https://develop.roblox.com/v1/groups/{}/universes",
+                group_id
+            ),
+            None => "https://develop.roblox.com/v1/user/universes".to_owned(),
+        };
+        let req = self.client().get(url).query(&[
+            ("limit", LIST_RECENT_EXPERIENCES_LIMIT.to_string()),
+            ("sortOrder", "Desc".to_owned()),
+        ]);
+
+        let res = with_retry(&self.retry_policy, &self.retry_budget, req, |req| {
+            self.handle_as_json::<ListExperiencesResponse>(req)
+        })
+        .await?;
+
+        Ok(res
+            .data
+            .into_iter()
+            .map(
+                |ListExperienceResponse { id, root_place_id }| CreateExperienceResponse {
+                    universe_id: id,
+                    root_place_id,
+                },
+            )
+            .collect())
     }
 
     pub async fn get_experience(
         &self,
         experience_id: AssetId,
     ) -> RobloxApiResult<GetExperienceResponse> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://develop.roblox.com/v1/universes/{}",
             experience_id
         ));
 
-        handle_as_json(req).await
+        with_retry(&self.retry_policy, &self.retry_budget, req, |req| {
+            self.handle_as_json(req)
+        })
+        .await
     }
 
     pub async fn get_experience_configuration(
         &self,
         experience_id: AssetId,
     ) -> RobloxApiResult<ExperienceConfigurationModel> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://develop.roblox.com/v1/universes/{}/configuration",
             experience_id
         ));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn configure_experience(
@@ -61,14 +125,86 @@ impl RobloxApi {
         experience_configuration: &ExperienceConfigurationModel,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .patch(format!(
                 "https://develop.roblox.com/v2/universes/{}/configuration",
                 experience_id
             ))
             .json(experience_configuration);
 
-        handle(req).await?;
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    /// Updates the avatar settings of an experience. Only the settings which are set on the model
+    /// are sent, so any others are left unchanged.
+    pub async fn configure_universe_avatar(
+        &self,
+        experience_id: AssetId,
+        avatar: &UniverseAvatarModel,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .patch(format!(
+                "https://develop.roblox.com/v2/universes/{}/configuration",
+                experience_id
+            ))
+            .json(avatar);
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    /// Updates the private server settings of an experience. Only the settings which are set on the
+    /// model are sent, so any others are left unchanged.
+    pub async fn configure_private_servers(
+        &self,
+        experience_id: AssetId,
+        settings: &PrivateServerSettingsModel,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .patch(format!(
+                "https://develop.roblox.com/v1/universes/{}/private-servers/settings",
+                experience_id
+            ))
+            .json(settings);
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    /// Archives the experience without changing any of its other configuration.
+    pub async fn archive_experience(&self, experience_id: AssetId) -> RobloxApiResult<()> {
+        let model = ExperienceConfigurationModel {
+            is_archived: Some(true),
+            ..Default::default()
+        };
+
+        self.configure_experience(experience_id, &model).await
+    }
+
+    /// Restarts the experience's servers so that players move to servers running the latest
+    /// published version of each place. Roblox only supports this through Open Cloud, so an API
+    /// key with write access to the experience is required.
+    pub async fn restart_experience_servers(
+        &self,
+        experience_id: AssetId,
+        open_cloud_api_key: &str,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .post(format!(
+                "https://apis.roblox.com/cloud/v2/universes/{}:restartServers",
+                experience_id
+            ))
+            .header("x-api-key", open_cloud_api_key)
+            .json(&json!({}));
+
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -80,15 +216,145 @@ impl RobloxApi {
     ) -> RobloxApiResult<()> {
         let endpoint = if active { "activate" } else { "deactivate" };
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://develop.roblox.com/v1/universes/{}/{}",
                 experience_id, endpoint
             ))
             .header(header::CONTENT_LENGTH, 0);
 
-        handle(req).await?;
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    pub async fn list_experience_permissions(
+        &self,
+        experience_id: AssetId,
+    ) -> RobloxApiResult<Vec<ExperienceCollaboratorPermission>> {
+        let req = self.client().get(format!(
+            "https://develop.roblox.com/v2/universes/{}/permissions",
+            experience_id
+        ));
+
+        Ok(self
+            .handle_as_json::<ListExperienceCollaboratorPermissionsResponse>(req)
+            .await?
+            .data)
+    }
+
+    /// Grants each of the permissions. Existing permissions which are not included are unchanged.
+    pub async fn set_experience_permissions(
+        &self,
+        experience_id: AssetId,
+        permissions: &[ExperienceCollaboratorPermission],
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .post(format!(
+                "https://develop.roblox.com/v2/universes/{}/permissions",
+                experience_id
+            ))
+            .json(permissions);
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_experience_permissions(
+        &self,
+        experience_id: AssetId,
+        permissions: &[ExperienceCollaboratorPermission],
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .delete(format!(
+                "https://develop.roblox.com/v2/universes/{}/permissions",
+                experience_id
+            ))
+            .json(permissions);
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    pub async fn list_experience_localized_metadata(
+        &self,
+        experience_id: AssetId,
+    ) -> RobloxApiResult<Vec<ExperienceLocalizedMetadata>> {
+        let req = self.client().get(format!(
+            "https://gameinternationalization.roblox.com/v1/name-description/games/{}",
+            experience_id
+        ));
+
+        Ok(self
+            .handle_as_json::<ListExperienceLocalizedMetadataResponse>(req)
+            .await?
+            .data)
+    }
+
+    pub async fn set_experience_localized_metadata(
+        &self,
+        experience_id: AssetId,
+        metadata: &ExperienceLocalizedMetadata,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .patch(format!(
+                "https://gameinternationalization.roblox.com/v1/name-description/games/{}",
+                experience_id
+            ))
+            .json(&json!({ "data": [metadata] }));
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_experience_localized_metadata(
+        &self,
+        experience_id: AssetId,
+        language_code: &str,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .delete(format!(
+                "https://gameinternationalization.roblox.com/v1/name-description/games/{}",
+                experience_id
+            ))
+            .json(&json!({ "languageCodes": [language_code] }));
+
+        self.handle(req).await?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+
+    use super::*;
+    use crate::http_client::tests::MockHttpClient;
+
+    #[tokio::test]
+    async fn archive_experience_only_sets_is_archived() {
+        let http_client = MockHttpClient::new(vec![(StatusCode::OK, json!({}))]);
+
+        http_client.api().archive_experience(123).await.unwrap();
+
+        let (method, url) = &http_client.requests()[0];
+        assert_eq!(*method, Method::PATCH);
+        assert_eq!(
+            url.as_str(),
+            "https://develop.roblox.com/v2/universes/123/configuration"
+        );
+        // Any other fields would overwrite the experience's configuration
+        assert_eq!(
+            http_client.request_bodies(),
+            vec![Some(json!({ "isArchived": true }))]
+        );
+    }
+}
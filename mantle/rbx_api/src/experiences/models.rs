@@ -9,113 +9,87 @@ pub struct CreateExperienceResponse {
     pub root_place_id: AssetId,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListExperiencesResponse {
+    pub data: Vec<ListExperienceResponse>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListExperienceResponse {
+    pub id: AssetId,
+    pub root_place_id: AssetId,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetExperienceResponse {
     pub root_place_id: AssetId,
     pub is_active: bool,
+    #[serde(default)]
+    pub is_archived: bool,
     pub creator_type: CreatorType,
     pub creator_target_id: AssetId,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+/// The configuration of an experience. Only the fields which are set are sent to Roblox, so any
+/// others are left unchanged.
+#[derive(Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ExperienceConfigurationModel {
-    pub genre: ExperienceGenre,
-    pub playable_devices: Vec<ExperiencePlayableDevice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<ExperienceGenre>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playable_devices: Option<Vec<ExperiencePlayableDevice>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_friends_only: Option<bool>,
 
-    #[serde(default)]
-    pub allow_private_servers: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_private_servers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub private_server_price: Option<u32>,
-    pub is_for_sale: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_for_sale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<u32>,
 
-    #[serde(default)]
-    pub studio_access_to_apis_allowed: bool,
-    #[serde(default)]
-    pub permissions: ExperiencePermissionsModel,
-
-    pub universe_avatar_type: ExperienceAvatarType,
-    pub universe_animation_type: ExperienceAnimationType,
-    pub universe_collision_type: ExperienceCollisionType,
-    #[serde(default = "default_min_scales")]
-    pub universe_avatar_min_scales: ExperienceAvatarScales,
-    #[serde(default = "default_max_scales")]
-    pub universe_avatar_max_scales: ExperienceAvatarScales,
-    #[serde(default = "default_asset_overrides")]
-    pub universe_avatar_asset_overrides: Vec<ExperienceAvatarAssetOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub studio_access_to_apis_allowed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ExperiencePermissionsModel>,
 
-    pub is_archived: bool,
-}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_avatar_type: Option<ExperienceAvatarType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_animation_type: Option<ExperienceAnimationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_collision_type: Option<ExperienceCollisionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_avatar_min_scales: Option<ExperienceAvatarScales>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_avatar_max_scales: Option<ExperienceAvatarScales>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_avatar_asset_overrides: Option<Vec<ExperienceAvatarAssetOverride>>,
 
-fn default_min_scales() -> ExperienceAvatarScales {
-    ExperienceConfigurationModel::default().universe_avatar_min_scales
-}
-
-fn default_max_scales() -> ExperienceAvatarScales {
-    ExperienceConfigurationModel::default().universe_avatar_max_scales
-}
-
-fn default_asset_overrides() -> Vec<ExperienceAvatarAssetOverride> {
-    ExperienceConfigurationModel::default().universe_avatar_asset_overrides
-}
-
-impl Default for ExperienceConfigurationModel {
-    fn default() -> Self {
-        ExperienceConfigurationModel {
-            genre: ExperienceGenre::All,
-            playable_devices: vec![
-                ExperiencePlayableDevice::Computer,
-                ExperiencePlayableDevice::Phone,
-                ExperiencePlayableDevice::Tablet,
-            ],
-            is_friends_only: Some(true),
-
-            allow_private_servers: false,
-            private_server_price: None,
-            is_for_sale: false,
-            price: None,
-
-            studio_access_to_apis_allowed: false,
-            permissions: ExperiencePermissionsModel {
-                is_third_party_purchase_allowed: false,
-                is_third_party_teleport_allowed: false,
-            },
-
-            universe_avatar_type: ExperienceAvatarType::MorphToR15,
-            universe_animation_type: ExperienceAnimationType::PlayerChoice,
-            universe_collision_type: ExperienceCollisionType::OuterBox,
-            universe_avatar_min_scales: ExperienceAvatarScales {
-                height: 0.9.to_string(),
-                width: 0.7.to_string(),
-                head: 0.95.to_string(),
-                body_type: 0.0.to_string(),
-                proportion: 0.0.to_string(),
-            },
-            universe_avatar_max_scales: ExperienceAvatarScales {
-                height: 1.05.to_string(),
-                width: 1.0.to_string(),
-                head: 1.0.to_string(),
-                body_type: 1.0.to_string(),
-                proportion: 1.0.to_string(),
-            },
-            universe_avatar_asset_overrides: vec![
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::Face),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::Head),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::Torso),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::LeftArm),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::RightArm),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::LeftLeg),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::RightLeg),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::TShirt),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::Shirt),
-                ExperienceAvatarAssetOverride::player_choice(AssetTypeId::Pants),
-            ],
-
-            is_archived: false,
-        }
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_archived: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_rating: Option<ExperienceAgeRating>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universe_joint_positioning_type: Option<ExperienceJointPositioningType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mesh_texture_api_access_allowed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_immersive_ads_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_rewarded_video_ads_enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailable_regions: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -139,6 +113,15 @@ pub enum ExperienceGenre {
     WildWest,
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "PascalCase")]
+pub enum ExperienceAgeRating {
+    Minimal,
+    Mild,
+    Moderate,
+    Restricted,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy)]
 #[serde(rename_all = "PascalCase")]
 pub enum ExperiencePlayableDevice {
@@ -149,7 +132,7 @@ pub enum ExperiencePlayableDevice {
     VR,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Copy)]
 pub enum ExperienceAvatarType {
     MorphToR6,
     MorphToR15,
@@ -170,6 +153,13 @@ pub enum ExperienceCollisionType {
     InnerBox,
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "PascalCase")]
+pub enum ExperienceJointPositioningType {
+    Standard,
+    ArtificialPhysics,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ExperienceAvatarScales {
@@ -180,6 +170,30 @@ pub struct ExperienceAvatarScales {
     pub proportion: String,
 }
 
+impl ExperienceAvatarScales {
+    /// Roblox's default minimum avatar scales.
+    pub fn default_min() -> Self {
+        Self {
+            height: 0.9.to_string(),
+            width: 0.7.to_string(),
+            head: 0.95.to_string(),
+            body_type: 0.0.to_string(),
+            proportion: 0.0.to_string(),
+        }
+    }
+
+    /// Roblox's default maximum avatar scales.
+    pub fn default_max() -> Self {
+        Self {
+            height: 1.05.to_string(),
+            width: 1.0.to_string(),
+            head: 1.0.to_string(),
+            body_type: 1.0.to_string(),
+            proportion: 1.0.to_string(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ExperienceAvatarAssetOverride {
@@ -197,11 +211,123 @@ impl ExperienceAvatarAssetOverride {
             asset_id: None,
         }
     }
+
+    /// Roblox's default asset overrides, which let players choose each of their avatar's assets.
+    pub fn defaults() -> Vec<Self> {
+        [
+            AssetTypeId::Face,
+            AssetTypeId::Head,
+            AssetTypeId::Torso,
+            AssetTypeId::LeftArm,
+            AssetTypeId::RightArm,
+            AssetTypeId::LeftLeg,
+            AssetTypeId::RightLeg,
+            AssetTypeId::TShirt,
+            AssetTypeId::Shirt,
+            AssetTypeId::Pants,
+        ]
+        .into_iter()
+        .map(Self::player_choice)
+        .collect()
+    }
+}
+
+/// Detailed avatar settings for an experience which are not part of the
+/// [`ExperienceConfigurationModel`]. Unset fields are omitted from the request so that they are
+/// left unchanged.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UniverseAvatarModel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub universe_avatar_type: Option<ExperienceAvatarType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub universe_avatar_body_colors: Option<UniverseAvatarBodyColors>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub universe_avatar_asset_overrides: Vec<ExperienceAvatarAssetOverride>,
+}
+
+/// Private server settings for an experience which are not part of the
+/// [`ExperienceConfigurationModel`]. Unset fields are omitted from the request so that they are
+/// left unchanged.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateServerSettingsModel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_link_sharing_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_subscribers_only: Option<bool>,
+}
+
+impl PrivateServerSettingsModel {
+    /// Whether any of the settings require private servers to be enabled.
+    pub fn requires_private_servers(&self) -> bool {
+        self.is_link_sharing_enabled == Some(true) || self.is_subscribers_only == Some(true)
+    }
+}
+
+/// BrickColor IDs for each body part.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UniverseAvatarBodyColors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_color_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub torso_color_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub left_arm_color_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub right_arm_color_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub left_leg_color_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub right_leg_color_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceLocalizedMetadata {
+    pub language_code: String,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListExperienceLocalizedMetadataResponse {
+    pub data: Vec<ExperienceLocalizedMetadata>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ExperienceCollaboratorSubjectType {
+    User,
+    GroupRoleset,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ExperienceCollaboratorAction {
+    Play,
+    Edit,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceCollaboratorPermission {
+    pub subject_type: ExperienceCollaboratorSubjectType,
+    pub subject_id: AssetId,
+    pub action: ExperienceCollaboratorAction,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListExperienceCollaboratorPermissionsResponse {
+    pub data: Vec<ExperienceCollaboratorPermission>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct ExperiencePermissionsModel {
-    pub is_third_party_purchase_allowed: bool,
-    pub is_third_party_teleport_allowed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_third_party_purchase_allowed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_third_party_teleport_allowed: Option<bool>,
 }
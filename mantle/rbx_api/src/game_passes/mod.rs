@@ -4,12 +4,7 @@ use std::path::PathBuf;
 
 use reqwest::multipart::Form;
 
-use crate::{
-    errors::RobloxApiResult,
-    helpers::{get_file_part, handle, handle_as_json},
-    models::AssetId,
-    RobloxApi,
-};
+use crate::{errors::RobloxApiResult, helpers::get_image_part, models::AssetId, RobloxApi};
 
 use self::models::{
     CreateGamePassResponse, GetGamePassResponse, ListGamePassResponse, ListGamePassesResponse,
@@ -22,7 +17,7 @@ impl RobloxApi {
         page_cursor: Option<String>,
     ) -> RobloxApiResult<ListGamePassesResponse> {
         let mut req = self
-            .client
+            .client()
             .get(format!(
                 "https://games.roblox.com/v1/games/{}/game-passes",
                 experience_id
@@ -32,19 +27,19 @@ impl RobloxApi {
             req = req.query(&[("cursor", &page_cursor)]);
         }
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn get_game_pass(
         &self,
         game_pass_id: AssetId,
     ) -> RobloxApiResult<GetGamePassResponse> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://economy.roblox.com/v1/game-pass/{}/game-pass-product-info",
             game_pass_id
         ));
 
-        let mut model = handle_as_json::<GetGamePassResponse>(req).await?;
+        let mut model = self.handle_as_json::<GetGamePassResponse>(req).await?;
         if model.target_id == 0 {
             model.target_id = game_pass_id;
         }
@@ -84,17 +79,17 @@ impl RobloxApi {
         icon_file: PathBuf,
     ) -> RobloxApiResult<CreateGamePassResponse> {
         let req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/game-passes/v1/game-passes")
             .multipart(
                 Form::new()
                     .text("Name", name.clone())
                     .text("Description", description.clone())
                     .text("UniverseId", experience_id.to_string())
-                    .part("File", get_file_part(icon_file).await?),
+                    .part("File", get_image_part(icon_file).await?),
             );
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn update_game_pass(
@@ -113,18 +108,18 @@ impl RobloxApi {
             form = form.text("price", price.to_string());
         }
         if let Some(icon_file) = icon_file {
-            form = form.part("file", get_file_part(icon_file).await?);
+            form = form.part("file", get_image_part(icon_file).await?);
         }
 
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://apis.roblox.com/game-passes/v1/game-passes/{}/details",
                 game_pass_id
             ))
             .multipart(form);
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         self.get_game_pass(game_pass_id).await
     }
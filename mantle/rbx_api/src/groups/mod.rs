@@ -2,12 +2,7 @@ pub mod models;
 
 use serde_json::json;
 
-use crate::{
-    errors::RobloxApiResult,
-    helpers::{handle, handle_as_json},
-    models::AssetId,
-    RobloxApi,
-};
+use crate::{errors::RobloxApiResult, models::AssetId, RobloxApi};
 
 use self::models::ListGroupRolesResponse;
 
@@ -20,14 +15,14 @@ impl RobloxApi {
         role_id: u64,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .patch(format!(
                 "https://groups.roblox.com/v1/groups/{}/users/{}",
                 group_id, user_id
             ))
             .json(&json!({ "roleId": role_id }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -36,11 +31,11 @@ impl RobloxApi {
         &self,
         group_id: AssetId,
     ) -> RobloxApiResult<ListGroupRolesResponse> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://groups.roblox.com/v1/groups/{}/roles",
             group_id
         ));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 }
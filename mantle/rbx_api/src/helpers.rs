@@ -1,13 +1,23 @@
-use std::{ffi::OsStr, path::PathBuf};
+use std::{
+    ffi::OsStr,
+    io::SeekFrom,
+    path::{Path, PathBuf},
+};
 
 use log::trace;
-use reqwest::{multipart::Part, Body};
+use reqwest::{multipart::Part, Body, StatusCode};
 use scraper::{Html, Selector};
 use serde::de;
-use tokio::fs::File;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+};
 use tokio_util::codec::{BytesCodec, FramedRead};
 
-use crate::{errors::RobloxApiErrorResponse, RobloxApiError, RobloxApiResult};
+use crate::{errors::RobloxApiErrorResponse, RobloxApi, RobloxApiError, RobloxApiResult};
+
+static CHALLENGE_ID_HEADER: &str = "rblx-challenge-id";
+static CHALLENGE_TYPE_HEADER: &str = "rblx-challenge-type";
 
 pub async fn get_roblox_api_error_from_response(response: reqwest::Response) -> RobloxApiError {
     let status_code = response.status();
@@ -48,61 +58,121 @@ pub async fn get_roblox_api_error_from_response(response: reqwest::Response) ->
     }
 }
 
-pub async fn handle(
-    request_builder: reqwest::RequestBuilder,
-) -> RobloxApiResult<reqwest::Response> {
-    let result = request_builder.send().await;
-    match result {
-        Ok(response) => {
-            // Check for redirects to the login page
-            let url = response.url();
-            if matches!(url.domain(), Some("www.roblox.com")) && url.path() == "/NewLogin" {
-                return Err(RobloxApiError::Authorization);
+impl RobloxApi {
+    pub(crate) async fn handle(
+        &self,
+        request_builder: reqwest::RequestBuilder,
+    ) -> RobloxApiResult<reqwest::Response> {
+        let result = self.execute(request_builder.build()?).await;
+        match result {
+            Ok(response) => {
+                // Check for redirects to the login page
+                let url = response.url();
+                if matches!(url.domain(), Some("www.roblox.com")) && url.path() == "/NewLogin" {
+                    return Err(RobloxApiError::Authorization);
+                }
+
+                // Check for challenges (e.g. two-step verification) which must be completed first
+                if response.headers().contains_key(CHALLENGE_ID_HEADER) {
+                    let challenge_type = response
+                        .headers()
+                        .get(CHALLENGE_TYPE_HEADER)
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or("unknown")
+                        .to_owned();
+                    return Err(RobloxApiError::ChallengeRequired { challenge_type });
+                }
+
+                // Check status code
+                if response.status().is_success() {
+                    Ok(response)
+                } else {
+                    match get_roblox_api_error_from_response(response).await {
+                        // The cookie (or the CSRF token derived from it) is no longer valid
+                        RobloxApiError::Roblox {
+                            status_code,
+                            reason,
+                        } if status_code == StatusCode::UNAUTHORIZED
+                            || (status_code == StatusCode::FORBIDDEN
+                                && reason.contains("Token Validation Failed")) =>
+                        {
+                            Err(RobloxApiError::AuthorizationExpired)
+                        }
+                        error => Err(error),
+                    }
+                }
             }
+            Err(error) => Err(error.into()),
+        }
+    }
 
-            // Check status code
-            if response.status().is_success() {
-                Ok(response)
-            } else {
-                Err(get_roblox_api_error_from_response(response).await)
+    pub(crate) async fn handle_as_json<T>(
+        &self,
+        request_builder: reqwest::RequestBuilder,
+    ) -> RobloxApiResult<T>
+    where
+        T: de::DeserializeOwned,
+    {
+        let res = self.handle(request_builder).await?;
+        let full = res.text().await?;
+        trace!("Handle JSON: {}", full);
+        serde_json::from_str::<T>(&full).map_err(|e| e.into())
+    }
+
+    pub(crate) async fn handle_as_json_with_status<T>(
+        &self,
+        request_builder: reqwest::RequestBuilder,
+    ) -> RobloxApiResult<T>
+    where
+        T: de::DeserializeOwned,
+    {
+        let response = self.handle(request_builder).await?;
+        let status_code = response.status();
+        let data = response.bytes().await?;
+        if let Ok(error) = serde_json::from_slice::<RobloxApiErrorResponse>(&data) {
+            if !error.success.unwrap_or(false) {
+                return Err(RobloxApiError::Roblox {
+                    status_code,
+                    reason: error.reason().unwrap_or_else(|| "Unknown error".to_owned()),
+                });
             }
         }
-        Err(error) => Err(error.into()),
+        Ok(serde_json::from_slice::<T>(&data)?)
     }
 }
 
-pub async fn handle_as_json<T>(request_builder: reqwest::RequestBuilder) -> RobloxApiResult<T>
-where
-    T: de::DeserializeOwned,
-{
-    let res = handle(request_builder).await?;
-    let full = res.text().await?;
-    trace!("Handle JSON: {}", full);
-    serde_json::from_str::<T>(&full).map_err(|e| e.into())
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+const JPEG_SIGNATURE: &[u8] = &[0xff, 0xd8, 0xff];
+
+/// Detects the content type of an image from its first bytes rather than its file extension,
+/// which may not match its contents. Roblox only accepts PNG and JPEG images for icons and
+/// thumbnails.
+fn get_image_content_type(file_path: &Path, header: &[u8]) -> RobloxApiResult<&'static str> {
+    if header.starts_with(PNG_SIGNATURE) {
+        Ok("image/png")
+    } else if header.starts_with(JPEG_SIGNATURE) {
+        Ok("image/jpeg")
+    } else {
+        Err(RobloxApiError::UnsupportedImageFormat(
+            file_path.display().to_string(),
+        ))
+    }
 }
 
-pub async fn handle_as_json_with_status<T>(
-    request_builder: reqwest::RequestBuilder,
-) -> RobloxApiResult<T>
-where
-    T: de::DeserializeOwned,
-{
-    let response = handle(request_builder).await?;
-    let status_code = response.status();
-    let data = response.bytes().await?;
-    if let Ok(error) = serde_json::from_slice::<RobloxApiErrorResponse>(&data) {
-        if !error.success.unwrap_or(false) {
-            return Err(RobloxApiError::Roblox {
-                status_code,
-                reason: error.reason().unwrap_or_else(|| "Unknown error".to_owned()),
-            });
+pub async fn get_image_part(file_path: PathBuf) -> RobloxApiResult<Part> {
+    let mut file = File::open(&file_path).await?;
+
+    let mut header = [0; 8];
+    let mut header_len = 0;
+    while header_len < header.len() {
+        match file.read(&mut header[header_len..]).await? {
+            0 => break,
+            read => header_len += read,
         }
     }
-    Ok(serde_json::from_slice::<T>(&data)?)
-}
+    let content_type = get_image_content_type(&file_path, &header[..header_len])?;
+    file.seek(SeekFrom::Start(0)).await?;
 
-pub async fn get_file_part(file_path: PathBuf) -> RobloxApiResult<Part> {
-    let file = File::open(&file_path).await?;
     let reader = Body::wrap_stream(FramedRead::new(file, BytesCodec::new()));
 
     let file_name = file_path
@@ -110,10 +180,39 @@ pub async fn get_file_part(file_path: PathBuf) -> RobloxApiResult<Part> {
         .and_then(OsStr::to_str)
         .ok_or_else(|| RobloxApiError::NoFileName(file_path.display().to_string()))?
         .to_owned();
-    let mime = mime_guess::from_path(&file_path).first_or_octet_stream();
 
     Ok(Part::stream(reader)
         .file_name(file_name)
-        .mime_str(mime.as_ref())
+        .mime_str(content_type)
         .unwrap())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_content_type_is_detected_from_contents() {
+        // The extensions do not match the contents, so they must be ignored
+        let png = [PNG_SIGNATURE, b"rest of image"].concat();
+        assert_eq!(
+            get_image_content_type(Path::new("icon.jpg"), &png).unwrap(),
+            "image/png"
+        );
+        let jpeg = [JPEG_SIGNATURE, b"rest of image"].concat();
+        assert_eq!(
+            get_image_content_type(Path::new("icon.png"), &jpeg).unwrap(),
+            "image/jpeg"
+        );
+    }
+
+    #[test]
+    fn image_content_type_rejects_other_formats() {
+        for header in [&b"GIF89a"[..], &b""[..], &PNG_SIGNATURE[..4]] {
+            assert!(matches!(
+                get_image_content_type(Path::new("icon.png"), header),
+                Err(RobloxApiError::UnsupportedImageFormat(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+
+/// Sends the requests made by [`RobloxApi`](crate::RobloxApi). By default requests are sent with
+/// the authenticated `reqwest` client, but another implementation can be provided with
+/// [`RobloxApi::with_http_client`](crate::RobloxApi::with_http_client), for example to return
+/// canned responses in tests without making real requests to Roblox.
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response>;
+}
+
+#[async_trait]
+impl HttpClient for reqwest::Client {
+    async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+        reqwest::Client::execute(self, request).await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    };
+
+    use rbx_auth::RobloxAuth;
+    use reqwest::{Method, StatusCode, Url};
+
+    use super::*;
+    use crate::{errors::RobloxApiError, retry::RetryPolicy, RobloxApi};
+
+    /// Responds to each request with the next canned JSON response and records the requests
+    /// which were sent, along with their JSON bodies.
+    #[derive(Clone, Default)]
+    pub(crate) struct MockHttpClient {
+        responses: Arc<Mutex<VecDeque<(StatusCode, String)>>>,
+        requests: Arc<Mutex<Vec<(Method, Url)>>>,
+        request_bodies: Arc<Mutex<Vec<Option<serde_json::Value>>>>,
+    }
+
+    impl MockHttpClient {
+        pub(crate) fn new(responses: Vec<(StatusCode, serde_json::Value)>) -> Self {
+            Self {
+                responses: Arc::new(Mutex::new(
+                    responses
+                        .into_iter()
+                        .map(|(status_code, body)| (status_code, body.to_string()))
+                        .collect(),
+                )),
+                ..Default::default()
+            }
+        }
+
+        pub(crate) fn requests(&self) -> Vec<(Method, Url)> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        /// The JSON body of each request, or `None` if it did not have one.
+        pub(crate) fn request_bodies(&self) -> Vec<Option<serde_json::Value>> {
+            self.request_bodies.lock().unwrap().clone()
+        }
+
+        /// Creates a client which sends its requests to this mock and never retries them.
+        pub(crate) fn api(&self) -> RobloxApi {
+            let roblox_auth = RobloxAuth {
+                jar: Default::default(),
+                headers: Default::default(),
+            };
+            RobloxApi::new_with_proxy(roblox_auth, None)
+                .unwrap()
+                .with_http_client(self.clone())
+                .with_retry_policy(RetryPolicy::none())
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for MockHttpClient {
+        async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((request.method().clone(), request.url().clone()));
+            self.request_bodies.lock().unwrap().push(
+                request
+                    .body()
+                    .and_then(|body| body.as_bytes())
+                    .and_then(|bytes| serde_json::from_slice(bytes).ok()),
+            );
+            let (status_code, body) = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| panic!("Unexpected request to {}", request.url()));
+
+            Ok(http::Response::builder()
+                .status(status_code)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body)
+                .unwrap()
+                .into())
+        }
+    }
+
+    #[tokio::test]
+    async fn sends_requests_with_the_http_client() {
+        let http_client = MockHttpClient::new(vec![(
+            StatusCode::OK,
+            serde_json::json!({
+                "rootPlaceId": 2,
+                "isActive": true,
+                "creatorType": "User",
+                "creatorTargetId": 3,
+            }),
+        )]);
+
+        let experience = http_client.api().get_experience(1).await.unwrap();
+
+        assert_eq!(experience.root_place_id, 2);
+        assert!(experience.is_active);
+        assert_eq!(
+            http_client.requests(),
+            vec![(
+                Method::GET,
+                Url::parse("https://develop.roblox.com/v1/universes/1").unwrap()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn maps_error_responses() {
+        let http_client = MockHttpClient::new(vec![(
+            StatusCode::NOT_FOUND,
+            serde_json::json!({
+                "errors": [{ "code": 1, "message": "The universe does not exist." }],
+            }),
+        )]);
+
+        let result = http_client.api().get_experience(1).await;
+
+        match result {
+            Err(RobloxApiError::Roblox {
+                status_code,
+                reason,
+            }) => {
+                assert_eq!(status_code, StatusCode::NOT_FOUND);
+                assert_eq!(reason, "The universe does not exist.");
+            }
+            _ => panic!("Expected a Roblox error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_unauthorized_responses_to_expired_authorization() {
+        let http_client = MockHttpClient::new(vec![(
+            StatusCode::UNAUTHORIZED,
+            serde_json::json!({ "errors": [{ "code": 0, "message": "Authorization has been denied for this request." }] }),
+        )]);
+
+        let result = http_client.api().get_experience(1).await;
+
+        assert!(matches!(result, Err(RobloxApiError::AuthorizationExpired)));
+    }
+}
@@ -3,46 +3,130 @@ pub mod asset_permissions;
 pub mod assets;
 pub mod badges;
 pub mod developer_products;
+pub mod developer_subscriptions;
 pub mod errors;
 pub mod experiences;
 pub mod game_passes;
 pub mod groups;
 mod helpers;
+pub mod http_client;
 pub mod models;
 pub mod notifications;
 pub mod places;
+pub mod proxy;
+pub mod retry;
 pub mod social_links;
 pub mod spatial_voice;
 pub mod thumbnails;
 
+use std::sync::{Arc, RwLock};
+
 use errors::{RobloxApiError, RobloxApiResult};
-use helpers::handle;
+use http_client::HttpClient;
+use proxy::ProxyConfig;
 use rbx_auth::{RobloxAuth, WithRobloxAuth};
+use retry::{RetryBudget, RetryPolicy};
 
 pub struct RobloxApi {
-    client: reqwest::Client,
+    client: RwLock<reqwest::Client>,
+    http_client: Option<Arc<dyn HttpClient>>,
+    proxy: Option<ProxyConfig>,
+    retry_policy: RetryPolicy,
+    retry_budget: RetryBudget,
+}
+
+fn build_client(
+    roblox_auth: RobloxAuth,
+    proxy: &Option<ProxyConfig>,
+) -> RobloxApiResult<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .connection_verbose(true)
+        .user_agent("Roblox/WinInet");
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(proxy.to_proxy()?);
+    }
+
+    Ok(builder.roblox_auth(roblox_auth).build()?)
 }
 
 impl RobloxApi {
+    /// Creates a client which uses the proxy from [`ProxyConfig::from_environment`], if any.
     pub fn new(roblox_auth: RobloxAuth) -> RobloxApiResult<Self> {
+        Self::new_with_proxy(roblox_auth, ProxyConfig::from_environment())
+    }
+
+    /// Creates a client which sends all requests through the provided proxy. If no proxy is
+    /// provided, the standard `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables
+    /// are used.
+    pub fn new_with_proxy(
+        roblox_auth: RobloxAuth,
+        proxy: Option<ProxyConfig>,
+    ) -> RobloxApiResult<Self> {
         Ok(Self {
-            client: reqwest::Client::builder()
-                .connection_verbose(true)
-                .user_agent("Roblox/WinInet")
-                .roblox_auth(roblox_auth)
-                .build()?,
+            client: RwLock::new(build_client(roblox_auth, &proxy)?),
+            http_client: None,
+            proxy,
+            retry_policy: RetryPolicy::default(),
+            retry_budget: RetryBudget::default(),
         })
     }
 
+    fn client(&self) -> reqwest::Client {
+        // Clients are reference counted, so this is cheap
+        self.client.read().unwrap().clone()
+    }
+
+    /// Sends all requests with the provided HTTP client instead of the authenticated `reqwest`
+    /// client.
+    pub fn with_http_client(mut self, http_client: impl HttpClient + 'static) -> Self {
+        self.http_client = Some(Arc::new(http_client));
+        self
+    }
+
+    async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+        match &self.http_client {
+            Some(http_client) => http_client.execute(request).await,
+            None => self.client().execute(request).await,
+        }
+    }
+
+    /// Overrides the policy used to retry idempotent requests which fail with transient errors.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Overrides the total number of retries which may be made across all requests. Defaults to
+    /// [`retry::DEFAULT_RETRY_BUDGET`].
+    pub fn with_retry_budget(mut self, max_retries: u32) -> Self {
+        self.retry_budget = RetryBudget::new(max_retries);
+        self
+    }
+
     pub async fn validate_auth(&self) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .get("https://users.roblox.com/v1/users/authenticated");
 
-        handle(req)
+        self.handle(req)
             .await
             .map_err(|_| RobloxApiError::Authorization)?;
 
         Ok(())
     }
+
+    /// Reloads the ROBLOSECURITY cookie from its source (in case it was refreshed after the
+    /// previous cookie expired) and uses it for all future requests. Returns an
+    /// `AuthorizationExpired` error if the reloaded cookie is not authorized either.
+    pub async fn reauthenticate(&self) -> RobloxApiResult<()> {
+        let roblox_auth = RobloxAuth::new()
+            .await
+            .map_err(|_| RobloxApiError::AuthorizationExpired)?;
+        let client = build_client(roblox_auth, &self.proxy)?;
+        *self.client.write().unwrap() = client;
+
+        self.validate_auth()
+            .await
+            .map_err(|_| RobloxApiError::AuthorizationExpired)
+    }
 }
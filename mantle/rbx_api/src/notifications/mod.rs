@@ -2,12 +2,7 @@ pub mod models;
 
 use serde_json::json;
 
-use crate::{
-    errors::RobloxApiResult,
-    helpers::{handle, handle_as_json},
-    models::AssetId,
-    RobloxApi,
-};
+use crate::{errors::RobloxApiResult, models::AssetId, RobloxApi};
 
 use self::models::{
     CreateNotificationResponse, ListNotificationResponse, ListNotificationsResponse,
@@ -21,7 +16,7 @@ impl RobloxApi {
         content: String,
     ) -> RobloxApiResult<CreateNotificationResponse> {
         let req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/notifications/v1/developer-configuration/create-notification")
             .json(&json!({
                 "universeId": experience_id,
@@ -29,7 +24,7 @@ impl RobloxApi {
                 "content": content,
             }));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn update_notification(
@@ -39,7 +34,7 @@ impl RobloxApi {
         content: String,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/notifications/v1/developer-configuration/update-notification")
             .json(&json!({
                 "id": notification_id,
@@ -47,20 +42,20 @@ impl RobloxApi {
                 "content": content,
             }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
 
     pub async fn archive_notification(&self, notification_id: String) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post("https://apis.roblox.com/notifications/v1/developer-configuration/archive-notification")
             .json(&json!({
                 "id": notification_id,
             }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -72,7 +67,7 @@ impl RobloxApi {
         page_cursor: Option<String>,
     ) -> RobloxApiResult<ListNotificationsResponse> {
         let mut req = self
-            .client
+            .client()
             .get("https://apis.roblox.com/notifications/v1/developer-configuration/experience-notifications-list")
             .query(&[
                 ("universeId", &experience_id.to_string()),
@@ -82,7 +77,7 @@ impl RobloxApi {
             req = req.query(&[("cursor", &page_cursor)]);
         }
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn get_all_notifications(
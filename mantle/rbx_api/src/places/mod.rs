@@ -1,14 +1,23 @@
 pub mod models;
 
-use std::{fs, path::PathBuf};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
-use reqwest::{Body, StatusCode};
+use futures::{future::try_join_all, StreamExt};
+use reqwest::{header, Body, StatusCode};
 use serde_json::json;
+use tokio::fs::File;
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
     errors::{RobloxApiError, RobloxApiResult},
-    helpers::{handle, handle_as_json, handle_as_json_with_status},
     models::AssetId,
+    retry::{with_create_reconciliation, with_retry},
     RobloxApi,
 };
 
@@ -17,96 +26,179 @@ use self::models::{
     PlaceConfigurationModel, PlaceFileFormat, RemovePlaceResponse,
 };
 
+/// The largest page size supported by the list places endpoint.
+const LIST_PLACES_PAGE_SIZE: u32 = 100;
+
+/// The number of place details which are fetched at the same time when listing places.
+const LIST_PLACES_CONCURRENCY: usize = 10;
+
+/// The baseplate template which new places are created from by default.
+pub const DEFAULT_PLACE_TEMPLATE_ID: AssetId = 95206881;
+
+/// Called with the number of bytes sent so far and the total number of bytes as a place file is
+/// uploaded.
+pub type UploadProgressCallback = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+/// Roblox reports place files which are too large with a 413, or sometimes a 404, status code.
+fn map_upload_place_error(
+    file_format: PlaceFileFormat,
+    result: RobloxApiResult<reqwest::Response>,
+) -> RobloxApiResult<()> {
+    match result {
+        Err(RobloxApiError::Roblox {
+            status_code,
+            reason,
+        }) => match (file_format, status_code) {
+            (PlaceFileFormat::Xml, StatusCode::PAYLOAD_TOO_LARGE) => {
+                Err(RobloxApiError::RbxlxPlaceFileSizeTooLarge)
+            }
+            (PlaceFileFormat::Xml, StatusCode::NOT_FOUND) => {
+                Err(RobloxApiError::RbxlxPlaceFileSizeMayBeTooLarge)
+            }
+            (PlaceFileFormat::Binary, StatusCode::PAYLOAD_TOO_LARGE) => {
+                Err(RobloxApiError::RbxlPlaceFileSizeTooLarge)
+            }
+            (PlaceFileFormat::Binary, StatusCode::NOT_FOUND) => {
+                Err(RobloxApiError::RbxlPlaceFileSizeMayBeTooLarge)
+            }
+            _ => Err(RobloxApiError::Roblox {
+                status_code,
+                reason,
+            }),
+        },
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
 impl RobloxApi {
+    /// Uploads a place file. The format is detected from the file's extension unless
+    /// `file_format` is provided, but the extension must always be `.rbxl` or `.rbxlx`.
     pub async fn upload_place(
         &self,
         place_file: PathBuf,
         place_id: AssetId,
+        file_format: Option<PlaceFileFormat>,
     ) -> RobloxApiResult<()> {
-        let file_format = match place_file.extension().and_then(|e| e.to_str()) {
-            Some("rbxl") => PlaceFileFormat::Binary,
-            Some("rbxlx") => PlaceFileFormat::Xml,
-            _ => {
-                return Err(RobloxApiError::InvalidFileExtension(
-                    place_file.display().to_string(),
-                ))
+        self.upload_place_with_progress(place_file, place_id, file_format, None)
+            .await
+    }
+
+    /// Uploads a place file like [`RobloxApi::upload_place`], calling `progress` as the file is
+    /// sent. The file is streamed from disk rather than read into memory, so the upload is not
+    /// retried if it fails.
+    pub async fn upload_place_with_progress(
+        &self,
+        place_file: PathBuf,
+        place_id: AssetId,
+        file_format: Option<PlaceFileFormat>,
+        progress: Option<UploadProgressCallback>,
+    ) -> RobloxApiResult<()> {
+        let detected_format = PlaceFileFormat::from_path(&place_file)?;
+        let file_format = file_format.unwrap_or(detected_format);
+
+        let file = File::open(&place_file).await?;
+        let total_bytes = file.metadata().await?.len();
+        let sent_bytes = AtomicU64::new(0);
+        let stream = FramedRead::new(file, BytesCodec::new()).inspect(move |chunk| {
+            if let (Ok(chunk), Some(progress)) = (chunk, &progress) {
+                let sent =
+                    sent_bytes.fetch_add(chunk.len() as u64, Ordering::SeqCst) + chunk.len() as u64;
+                progress(sent, total_bytes);
             }
-        };
+        });
 
-        let data = fs::read(&place_file)?;
+        let req = self
+            .client()
+            .post("https://data.roblox.com/Data/Upload.ashx")
+            .query(&[("assetId", place_id.to_string())])
+            .header("Content-Type", file_format.content_type())
+            .header(header::CONTENT_LENGTH, total_bytes)
+            .body(Body::wrap_stream(stream));
 
-        let body: Body = match file_format {
-            PlaceFileFormat::Binary => data.into(),
-            PlaceFileFormat::Xml => String::from_utf8(data)?.into(),
+        map_upload_place_error(file_format, self.handle(req).await)
+    }
+
+    /// Uploads a place file which is already in memory. Because there is no file extension to
+    /// check, the caller must declare whether the data is in the XML (rbxlx) or binary (rbxl)
+    /// format.
+    pub async fn upload_place_bytes(
+        &self,
+        bytes: &[u8],
+        place_id: AssetId,
+        is_xml: bool,
+    ) -> RobloxApiResult<()> {
+        let file_format = if is_xml {
+            PlaceFileFormat::Xml
+        } else {
+            PlaceFileFormat::Binary
         };
 
-        let content_type = match file_format {
-            PlaceFileFormat::Binary => "application/octet-stream",
-            PlaceFileFormat::Xml => "application/xml",
+        let body: Body = match file_format {
+            PlaceFileFormat::Binary => bytes.to_vec().into(),
+            PlaceFileFormat::Xml => String::from_utf8(bytes.to_vec())?.into(),
         };
 
         let req = self
-            .client
+            .client()
             .post("https://data.roblox.com/Data/Upload.ashx")
             .query(&[("assetId", place_id.to_string())])
-            .header("Content-Type", content_type)
+            .header("Content-Type", file_format.content_type())
             .body(body);
 
-        let result = handle(req).await;
+        // Uploads are not retried because each one publishes a new version of the place.
+        let result = self.handle(req).await;
 
-        match result {
-            Err(RobloxApiError::Roblox {
-                status_code,
-                reason,
-            }) => match (file_format, status_code) {
-                (PlaceFileFormat::Xml, StatusCode::PAYLOAD_TOO_LARGE) => {
-                    Err(RobloxApiError::RbxlxPlaceFileSizeTooLarge)
-                }
-                (PlaceFileFormat::Xml, StatusCode::NOT_FOUND) => {
-                    Err(RobloxApiError::RbxlxPlaceFileSizeMayBeTooLarge)
-                }
-                (PlaceFileFormat::Binary, StatusCode::PAYLOAD_TOO_LARGE) => {
-                    Err(RobloxApiError::RbxlPlaceFileSizeTooLarge)
-                }
-                (PlaceFileFormat::Binary, StatusCode::NOT_FOUND) => {
-                    Err(RobloxApiError::RbxlPlaceFileSizeMayBeTooLarge)
-                }
-                _ => Err(RobloxApiError::Roblox {
-                    status_code,
-                    reason,
-                }),
-            },
-            Err(e) => Err(e),
-            Ok(_) => Ok(()),
-        }
+        map_upload_place_error(file_format, result)
+    }
+
+    /// Publishes a previous version of a place again. Roblox saves the reverted place as a new
+    /// version rather than removing the versions after it.
+    pub async fn rollback_place(&self, place_id: AssetId, version: u64) -> RobloxApiResult<()> {
+        let req = self.client().post(format!(
+            "https://develop.roblox.com/v1/places/{}/versions/{}/revert",
+            place_id, version
+        ));
+
+        self.handle(req).await?;
+
+        Ok(())
     }
 
     pub async fn get_place(&self, place_id: AssetId) -> RobloxApiResult<GetPlaceResponse> {
         let req = self
-            .client
+            .client()
             .get(format!("https://develop.roblox.com/v2/places/{}", place_id));
 
-        handle_as_json(req).await
+        with_retry(&self.retry_policy, &self.retry_budget, req, |req| {
+            self.handle_as_json(req)
+        })
+        .await
     }
 
-    pub async fn list_places(
+    pub async fn list_places_page(
         &self,
         experience_id: AssetId,
         page_cursor: Option<String>,
     ) -> RobloxApiResult<ListPlacesResponse> {
-        let mut req = self.client.get(format!(
-            "https://develop.roblox.com/v1/universes/{}/places",
-            experience_id
-        ));
+        let mut req = self
+            .client()
+            .get(format!(
+                "https://develop.roblox.com/v1/universes/{}/places",
+                experience_id
+            ))
+            .query(&[("limit", LIST_PLACES_PAGE_SIZE)]);
         if let Some(page_cursor) = page_cursor {
             req = req.query(&[("cursor", &page_cursor)]);
         }
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
+    /// Gets the details of every place in an experience. The place IDs are listed in pages of
+    /// `LIST_PLACES_PAGE_SIZE` and their details are fetched `LIST_PLACES_CONCURRENCY` at a time.
     // TODO: implement generic form
-    pub async fn get_all_places(
+    pub async fn list_places(
         &self,
         experience_id: AssetId,
     ) -> RobloxApiResult<Vec<GetPlaceResponse>> {
@@ -114,10 +206,15 @@ impl RobloxApi {
 
         let mut page_cursor: Option<String> = None;
         loop {
-            let res = self.list_places(experience_id, page_cursor).await?;
-            for ListPlaceResponse { id } in res.data {
-                let place = self.get_place(id).await?;
-                all_places.push(place);
+            let res = self.list_places_page(experience_id, page_cursor).await?;
+            let ids = res
+                .data
+                .into_iter()
+                .map(|ListPlaceResponse { id }| id)
+                .collect::<Vec<_>>();
+            for chunk in ids.chunks(LIST_PLACES_CONCURRENCY) {
+                let places = try_join_all(chunk.iter().map(|id| self.get_place(*id))).await?;
+                all_places.extend(places);
             }
 
             if res.next_page_cursor.is_none() {
@@ -130,39 +227,124 @@ impl RobloxApi {
         Ok(all_places)
     }
 
+    /// Lists the IDs of every place in an experience without fetching their details.
+    pub async fn list_place_ids(&self, experience_id: AssetId) -> RobloxApiResult<Vec<AssetId>> {
+        let mut all_ids = Vec::new();
+
+        let mut page_cursor: Option<String> = None;
+        loop {
+            let res = self.list_places_page(experience_id, page_cursor).await?;
+            all_ids.extend(res.data.into_iter().map(|ListPlaceResponse { id }| id));
+
+            if res.next_page_cursor.is_none() {
+                break;
+            }
+
+            page_cursor = res.next_page_cursor;
+        }
+
+        Ok(all_ids)
+    }
+
     pub async fn remove_place_from_experience(
         &self,
         experience_id: AssetId,
         place_id: AssetId,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post("https://www.roblox.com/universes/removeplace")
             .form(&[
                 ("universeId", &experience_id.to_string()),
                 ("placeId", &place_id.to_string()),
             ]);
 
-        handle_as_json_with_status::<RemovePlaceResponse>(req).await?;
+        self.handle_as_json_with_status::<RemovePlaceResponse>(req)
+            .await?;
 
         Ok(())
     }
 
+    /// Makes a place which already belongs to the experience its start (root) place. The previous
+    /// start place is kept as a regular place in the experience.
+    pub async fn set_start_place(
+        &self,
+        experience_id: AssetId,
+        place_id: AssetId,
+    ) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .patch(format!(
+                "https://develop.roblox.com/v2/universes/{}/configuration",
+                experience_id
+            ))
+            .json(&json!({ "rootPlaceId": place_id }));
+
+        self.handle(req).await?;
+
+        Ok(())
+    }
+
+    /// Creates a new place in the experience from the default (baseplate) template.
     pub async fn create_place(
         &self,
         experience_id: AssetId,
+    ) -> RobloxApiResult<CreatePlaceResponse> {
+        self.create_place_from_template(experience_id, DEFAULT_PLACE_TEMPLATE_ID)
+            .await
+    }
+
+    /// Creates a new place in the experience as a copy of the template place. If the request fails
+    /// with a transient error, the experience's places are listed again so that a place which was
+    /// created by the failed request is returned rather than creating a second one.
+    pub async fn create_place_from_template(
+        &self,
+        experience_id: AssetId,
+        template_place_id: AssetId,
+    ) -> RobloxApiResult<CreatePlaceResponse> {
+        with_create_reconciliation(
+            self.create_place_from_template_once(experience_id, template_place_id),
+            || async {
+                self.list_place_ids(experience_id).await.map(|place_ids| {
+                    place_ids
+                        .into_iter()
+                        .map(|place_id| CreatePlaceResponse { place_id })
+                        .collect()
+                })
+            },
+            |place| place.place_id,
+        )
+        .await
+    }
+
+    async fn create_place_from_template_once(
+        &self,
+        experience_id: AssetId,
+        template_place_id: AssetId,
     ) -> RobloxApiResult<CreatePlaceResponse> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://apis.roblox.com/universes/v1/user/universes/{}/places",
                 experience_id
             ))
             .json(&json!({
-                "templatePlaceId": 95206881
+                "templatePlaceId": template_place_id
             }));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
+    }
+
+    /// Sets only the name of a place, leaving the rest of its configuration unchanged.
+    pub async fn set_place_name(&self, place_id: AssetId, name: &str) -> RobloxApiResult<()> {
+        let req = self
+            .client()
+            .patch(format!("https://develop.roblox.com/v2/places/{}", place_id))
+            .json(&json!({ "name": name }));
+
+        self.handle(req).await?;
+
+        Ok(())
     }
 
     pub async fn configure_place(
@@ -171,12 +353,88 @@ impl RobloxApi {
         place_configuration: &PlaceConfigurationModel,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .patch(format!("https://develop.roblox.com/v2/places/{}", place_id))
             .json(place_configuration);
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Method;
+
+    use super::*;
+    use crate::http_client::tests::MockHttpClient;
+
+    #[tokio::test]
+    async fn set_place_name_only_patches_the_place() {
+        let http_client = MockHttpClient::new(vec![(StatusCode::OK, json!({}))]);
+
+        http_client
+            .api()
+            .set_place_name(123, "Lobby")
+            .await
+            .unwrap();
+
+        let requests = http_client.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, Method::PATCH);
+        assert_eq!(
+            requests[0].1.as_str(),
+            "https://develop.roblox.com/v2/places/123"
+        );
+    }
+
+    fn places_page(place_ids: &[AssetId]) -> serde_json::Value {
+        json!({
+            "nextPageCursor": null,
+            "data": place_ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+        })
+    }
+
+    #[tokio::test]
+    async fn create_place_uses_the_default_template() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, places_page(&[1])),
+            (StatusCode::OK, json!({ "placeId": 2 })),
+        ]);
+
+        let place = http_client.api().create_place(100).await.unwrap();
+
+        assert_eq!(place.place_id, 2);
+        assert_eq!(
+            http_client.request_bodies()[1],
+            Some(json!({ "templatePlaceId": DEFAULT_PLACE_TEMPLATE_ID }))
+        );
+    }
+
+    #[tokio::test]
+    async fn create_place_from_template_copies_the_template() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, places_page(&[1])),
+            (StatusCode::OK, json!({ "placeId": 2 })),
+        ]);
+
+        let place = http_client
+            .api()
+            .create_place_from_template(100, 1234)
+            .await
+            .unwrap();
+
+        assert_eq!(place.place_id, 2);
+        let (method, url) = &http_client.requests()[1];
+        assert_eq!(*method, Method::POST);
+        assert_eq!(
+            url.as_str(),
+            "https://apis.roblox.com/universes/v1/user/universes/100/places"
+        );
+        assert_eq!(
+            http_client.request_bodies()[1],
+            Some(json!({ "templatePlaceId": 1234 }))
+        );
+    }
+}
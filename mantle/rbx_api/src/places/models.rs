@@ -1,6 +1,11 @@
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
-use crate::models::{AssetId, SocialSlotType};
+use crate::{
+    errors::{RobloxApiError, RobloxApiResult},
+    models::{AssetId, SocialSlotType},
+};
 
 pub const DEFAULT_PLACE_NAME: &str = "Untitled Game";
 
@@ -10,7 +15,7 @@ pub struct CreatePlaceResponse {
     pub place_id: AssetId,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPlaceResponse {
     pub id: AssetId,
@@ -33,6 +38,9 @@ impl From<GetPlaceResponse> for PlaceConfigurationModel {
             allow_copying: response.allow_copying,
             social_slot_type: response.social_slot_type,
             custom_social_slots_count: response.custom_social_slots_count,
+            streaming_enabled: None,
+            streaming_min_radius: None,
+            streaming_target_radius: None,
         }
     }
 }
@@ -46,6 +54,13 @@ pub struct PlaceConfigurationModel {
     pub allow_copying: bool,
     pub social_slot_type: SocialSlotType,
     pub custom_social_slots_count: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streaming_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streaming_min_radius: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streaming_target_radius: Option<u32>,
 }
 
 impl Default for PlaceConfigurationModel {
@@ -57,6 +72,9 @@ impl Default for PlaceConfigurationModel {
             allow_copying: false,
             social_slot_type: SocialSlotType::Automatic,
             custom_social_slots_count: None,
+            streaming_enabled: None,
+            streaming_min_radius: None,
+            streaming_target_radius: None,
         }
     }
 }
@@ -78,7 +96,66 @@ pub struct ListPlaceResponse {
     pub id: AssetId,
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub enum PlaceFileFormat {
     Xml,
     Binary,
 }
+
+impl PlaceFileFormat {
+    /// Determines the format of a place file from its extension: `.rbxlx` files are XML and
+    /// `.rbxl` files are binary. Any other extension is rejected.
+    pub fn from_path(place_file: &Path) -> RobloxApiResult<Self> {
+        match place_file.extension().and_then(|e| e.to_str()) {
+            Some("rbxl") => Ok(PlaceFileFormat::Binary),
+            Some("rbxlx") => Ok(PlaceFileFormat::Xml),
+            _ => Err(RobloxApiError::InvalidPlaceFileExtension(
+                place_file.display().to_string(),
+            )),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            PlaceFileFormat::Binary => "application/octet-stream",
+            PlaceFileFormat::Xml => "application/xml",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_path_detects_binary_and_xml_place_files() {
+        assert_eq!(
+            PlaceFileFormat::from_path(Path::new("places/start.rbxl")).unwrap(),
+            PlaceFileFormat::Binary
+        );
+        assert_eq!(
+            PlaceFileFormat::from_path(Path::new("places/start.rbxlx")).unwrap(),
+            PlaceFileFormat::Xml
+        );
+    }
+
+    #[test]
+    fn from_path_rejects_other_extensions() {
+        for path in ["places/start.rbxm", "places/start", "places/start.rbxl.txt"] {
+            assert!(matches!(
+                PlaceFileFormat::from_path(Path::new(path)),
+                Err(RobloxApiError::InvalidPlaceFileExtension(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn content_type_matches_format() {
+        assert_eq!(
+            PlaceFileFormat::Binary.content_type(),
+            "application/octet-stream"
+        );
+        assert_eq!(PlaceFileFormat::Xml.content_type(), "application/xml");
+    }
+}
@@ -0,0 +1,41 @@
+use std::env;
+
+use reqwest::Proxy;
+
+use crate::errors::RobloxApiResult;
+
+const PROXY_URL_VAR: &str = "MANTLE_PROXY_URL";
+const PROXY_USERNAME_VAR: &str = "MANTLE_PROXY_USERNAME";
+const PROXY_PASSWORD_VAR: &str = "MANTLE_PROXY_PASSWORD";
+
+/// An explicit proxy which all requests will be sent through. When no proxy is configured, the
+/// standard `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables are used instead.
+#[derive(Clone, Debug)]
+pub struct ProxyConfig {
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Loads the proxy from the `MANTLE_PROXY_URL`, `MANTLE_PROXY_USERNAME`, and
+    /// `MANTLE_PROXY_PASSWORD` environment variables. Returns `None` if no URL is set.
+    pub fn from_environment() -> Option<Self> {
+        let url = env::var(PROXY_URL_VAR).ok()?;
+        Some(Self {
+            url,
+            username: env::var(PROXY_USERNAME_VAR).ok(),
+            password: env::var(PROXY_PASSWORD_VAR).ok(),
+        })
+    }
+
+    pub(crate) fn to_proxy(&self) -> RobloxApiResult<Proxy> {
+        let proxy = Proxy::all(&self.url)?;
+        Ok(match &self.username {
+            Some(username) => {
+                proxy.basic_auth(username, self.password.as_deref().unwrap_or_default())
+            }
+            None => proxy,
+        })
+    }
+}
@@ -0,0 +1,322 @@
+use std::{
+    collections::HashSet,
+    future::Future,
+    hash::Hash,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use log::debug;
+use rand::Rng;
+
+use crate::errors::{RobloxApiError, RobloxApiResult};
+
+/// Controls how idempotent requests which fail with a transient transport error (i.e. the
+/// connection could not be made or the request timed out) are retried. Error responses from Roblox
+/// (e.g. 4xx status codes) are never retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request will be retried after the first attempt.
+    pub max_retries: u32,
+    /// The base delay which is doubled after each attempt.
+    pub base_delay: Duration,
+    /// The maximum delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries requests.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Uses "full jitter" (a random delay between zero and the exponential backoff) so that many
+    /// requests failing at the same time do not all retry at the same time.
+    fn get_delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let max_millis = backoff.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(0..=max_millis))
+    }
+}
+
+/// The default number of retries shared by all requests made by a client.
+pub const DEFAULT_RETRY_BUDGET: u32 = 20;
+
+/// Limits the total number of retries across every request made by a client, so that when Roblox
+/// is down the deployment fails quickly instead of retrying each request in turn. Once the budget
+/// is spent, transient errors are returned without retrying. Rate limit (429) responses are not
+/// retried here, so they never use the budget.
+#[derive(Debug)]
+pub struct RetryBudget {
+    max_retries: u32,
+    used: AtomicU32,
+}
+
+impl Default for RetryBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_RETRY_BUDGET)
+    }
+}
+
+impl RetryBudget {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            used: AtomicU32::new(0),
+        }
+    }
+
+    /// Returns whether a retry may be made, using one from the budget if so.
+    fn try_spend(&self) -> bool {
+        self.used
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                (used < self.max_retries).then_some(used + 1)
+            })
+            .is_ok()
+    }
+
+    /// The number of retries which have been made so far.
+    pub fn used(&self) -> u32 {
+        self.used.load(Ordering::SeqCst)
+    }
+}
+
+pub(crate) fn is_transient_error(error: &RobloxApiError) -> bool {
+    match error {
+        RobloxApiError::HttpClient(error) => error.is_timeout() || error.is_connect(),
+        _ => false,
+    }
+}
+
+fn is_idempotent(method: &reqwest::Method) -> bool {
+    matches!(*method, reqwest::Method::GET | reqwest::Method::HEAD)
+}
+
+/// Sends the request with the handler, retrying according to the policy while the budget allows.
+/// Requests which are not idempotent (i.e. anything other than GET and HEAD requests) and requests
+/// whose body cannot be cloned (e.g. streamed file uploads) are only sent once.
+pub(crate) async fn with_retry<T, F, Fut>(
+    policy: &RetryPolicy,
+    budget: &RetryBudget,
+    request_builder: reqwest::RequestBuilder,
+    handler: F,
+) -> RobloxApiResult<T>
+where
+    F: Fn(reqwest::RequestBuilder) -> Fut,
+    Fut: Future<Output = RobloxApiResult<T>>,
+{
+    let idempotent = matches!(
+        request_builder.try_clone().and_then(|request| request.build().ok()),
+        Some(request) if is_idempotent(request.method())
+    );
+    if !idempotent {
+        return handler(request_builder).await;
+    }
+
+    let mut attempt = 0;
+    loop {
+        let request = match request_builder.try_clone() {
+            Some(request) => request,
+            None => return handler(request_builder).await,
+        };
+
+        match handler(request).await {
+            Err(error) if attempt < policy.max_retries && is_transient_error(&error) => {
+                if !budget.try_spend() {
+                    return Err(RobloxApiError::RetryBudgetExhausted {
+                        retries: budget.used(),
+                        error: Box::new(error),
+                    });
+                }
+
+                let delay = policy.get_delay(attempt);
+                debug!(
+                    "Request failed with transient error, retrying in {}ms: {}",
+                    delay.as_millis(),
+                    error
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Sends a request which creates a resource. Roblox does not support idempotency keys, so when a
+/// create fails with a transient error the resource may have been created even though the response
+/// was lost. In that case the resources are listed again, and if exactly one resource was added
+/// since before the request it is returned instead of the error so that the caller does not create
+/// a second one. Otherwise (including when the resources cannot be listed), the original error is
+/// returned.
+pub(crate) async fn with_create_reconciliation<T, K, CFut, L, LFut>(
+    create: CFut,
+    list: L,
+    key: impl Fn(&T) -> K,
+) -> RobloxApiResult<T>
+where
+    CFut: Future<Output = RobloxApiResult<T>>,
+    L: Fn() -> LFut,
+    LFut: Future<Output = RobloxApiResult<Vec<T>>>,
+    K: Eq + Hash,
+{
+    let existing = list().await?.iter().map(&key).collect::<HashSet<_>>();
+
+    match create.await {
+        Err(error) if is_transient_error(&error) => {
+            let resources = match list().await {
+                Ok(resources) => resources,
+                Err(_) => return Err(error),
+            };
+            let mut created = resources
+                .into_iter()
+                .filter(|resource| !existing.contains(&key(resource)));
+            match (created.next(), created.next()) {
+                (Some(resource), None) => {
+                    debug!(
+                        "Create request failed with transient error, but the resource was created: {}",
+                        error
+                    );
+                    Ok(resource)
+                }
+                _ => Err(error),
+            }
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use reqwest::StatusCode;
+
+    use super::*;
+
+    /// Nothing listens on port 1, so connections to it are refused.
+    const UNREACHABLE_URL: &str = "http://127.0.0.1:1";
+
+    fn immediate_retries(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+        }
+    }
+
+    /// Sends the request with retries and returns the result along with the number of attempts.
+    async fn send(
+        policy: &RetryPolicy,
+        request: reqwest::RequestBuilder,
+    ) -> (RobloxApiResult<reqwest::Response>, u32) {
+        send_with_budget(policy, &RetryBudget::default(), request).await
+    }
+
+    async fn send_with_budget(
+        policy: &RetryPolicy,
+        budget: &RetryBudget,
+        request: reqwest::RequestBuilder,
+    ) -> (RobloxApiResult<reqwest::Response>, u32) {
+        let attempts = AtomicU32::new(0);
+        let result = with_retry(policy, budget, request, |request| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(request.send().await?) }
+        })
+        .await;
+        (result, attempts.into_inner())
+    }
+
+    #[tokio::test]
+    async fn zero_retry_policy_sends_requests_once() {
+        let request = reqwest::Client::new().get(UNREACHABLE_URL);
+
+        let (result, attempts) = send(&RetryPolicy::none(), request).await;
+
+        assert!(matches!(&result, Err(error) if is_transient_error(error)));
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn transient_errors_are_retried_up_to_the_policy_limit() {
+        let request = reqwest::Client::new().get(UNREACHABLE_URL);
+
+        let (result, attempts) = send(&immediate_retries(2), request).await;
+
+        assert!(matches!(&result, Err(error) if is_transient_error(error)));
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_once_the_shared_budget_is_used_up() {
+        let budget = RetryBudget::new(1);
+        let client = reqwest::Client::new();
+
+        let (first_result, first_attempts) =
+            send_with_budget(&immediate_retries(2), &budget, client.get(UNREACHABLE_URL)).await;
+        let (second_result, second_attempts) =
+            send_with_budget(&immediate_retries(2), &budget, client.get(UNREACHABLE_URL)).await;
+
+        assert!(matches!(
+            first_result,
+            Err(RobloxApiError::RetryBudgetExhausted { retries: 1, .. })
+        ));
+        assert_eq!(first_attempts, 2);
+        assert!(matches!(
+            second_result,
+            Err(RobloxApiError::RetryBudgetExhausted { retries: 1, .. })
+        ));
+        assert_eq!(second_attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn non_idempotent_requests_are_not_retried() {
+        let request = reqwest::Client::new().post(UNREACHABLE_URL);
+
+        let (result, attempts) = send(&immediate_retries(2), request).await;
+
+        assert!(matches!(&result, Err(error) if is_transient_error(error)));
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn error_responses_are_not_retried() {
+        let attempts = AtomicU32::new(0);
+
+        let result: RobloxApiResult<()> = with_retry(
+            &immediate_retries(2),
+            &RetryBudget::default(),
+            reqwest::Client::new().get(UNREACHABLE_URL),
+            |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async {
+                    Err(RobloxApiError::Roblox {
+                        status_code: StatusCode::SERVICE_UNAVAILABLE,
+                        reason: "Service unavailable".to_owned(),
+                    })
+                }
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(RobloxApiError::Roblox { .. })));
+        assert_eq!(attempts.into_inner(), 1);
+    }
+}
@@ -2,12 +2,7 @@ pub mod models;
 
 use serde_json::json;
 
-use crate::{
-    errors::RobloxApiResult,
-    helpers::{handle, handle_as_json},
-    models::AssetId,
-    RobloxApi,
-};
+use crate::{errors::RobloxApiResult, models::AssetId, RobloxApi};
 
 use self::models::{
     CreateSocialLinkResponse, GetSocialLinkResponse, ListSocialLinksResponse, SocialLinkType,
@@ -22,7 +17,7 @@ impl RobloxApi {
         link_type: SocialLinkType,
     ) -> RobloxApiResult<CreateSocialLinkResponse> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://develop.roblox.com/v1/universes/{}/social-links",
                 experience_id
@@ -33,7 +28,7 @@ impl RobloxApi {
                 "type": link_type,
             }));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn update_social_link(
@@ -45,7 +40,7 @@ impl RobloxApi {
         link_type: SocialLinkType,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .patch(format!(
                 "https://develop.roblox.com/v1/universes/{}/social-links/{}",
                 experience_id, social_link_id
@@ -56,7 +51,7 @@ impl RobloxApi {
                 "type": link_type,
             }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -66,12 +61,12 @@ impl RobloxApi {
         experience_id: AssetId,
         social_link_id: AssetId,
     ) -> RobloxApiResult<()> {
-        let req = self.client.delete(format!(
+        let req = self.client().delete(format!(
             "https://develop.roblox.com/v1/universes/{}/social-links/{}",
             experience_id, social_link_id
         ));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -80,11 +75,14 @@ impl RobloxApi {
         &self,
         experience_id: AssetId,
     ) -> RobloxApiResult<Vec<GetSocialLinkResponse>> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://games.roblox.com/v1/games/{}/social-links/list",
             experience_id
         ));
 
-        Ok(handle_as_json::<ListSocialLinksResponse>(req).await?.data)
+        Ok(self
+            .handle_as_json::<ListSocialLinksResponse>(req)
+            .await?
+            .data)
     }
 }
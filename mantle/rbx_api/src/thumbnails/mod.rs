@@ -7,7 +7,7 @@ use serde_json::json;
 
 use crate::{
     errors::RobloxApiResult,
-    helpers::{get_file_part, handle, handle_as_json},
+    helpers::get_image_part,
     models::{AssetId, UploadImageResponse},
     RobloxApi,
 };
@@ -22,14 +22,14 @@ impl RobloxApi {
         icon_file: PathBuf,
     ) -> RobloxApiResult<UploadImageResponse> {
         let req = self
-            .client
+            .client()
             .post(&format!(
                 "https://publish.roblox.com/v1/games/{}/icon",
                 experience_id
             ))
-            .multipart(Form::new().part("request.files", get_file_part(icon_file).await?));
+            .multipart(Form::new().part("request.files", get_image_part(icon_file).await?));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
     }
 
     pub async fn upload_thumbnail(
@@ -38,14 +38,32 @@ impl RobloxApi {
         thumbnail_file: PathBuf,
     ) -> RobloxApiResult<UploadImageResponse> {
         let req = self
-            .client
+            .client()
             .post(&format!(
                 "https://publish.roblox.com/v1/games/{}/thumbnail/image",
                 experience_id
             ))
-            .multipart(Form::new().part("request.files", get_file_part(thumbnail_file).await?));
+            .multipart(Form::new().part("request.files", get_image_part(thumbnail_file).await?));
 
-        handle_as_json(req).await
+        self.handle_as_json(req).await
+    }
+
+    /// Adds an existing asset (e.g. a video) to an experience's thumbnails without uploading a
+    /// file.
+    pub async fn add_thumbnail_asset(
+        &self,
+        experience_id: AssetId,
+        asset_id: AssetId,
+    ) -> RobloxApiResult<UploadImageResponse> {
+        let req = self
+            .client()
+            .post(format!(
+                "https://publish.roblox.com/v1/games/{}/thumbnail/video",
+                experience_id
+            ))
+            .json(&json!({ "videoAssetId": asset_id }));
+
+        self.handle_as_json(req).await
     }
 
     pub async fn remove_experience_icon(
@@ -54,14 +72,14 @@ impl RobloxApi {
         icon_asset_id: AssetId,
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post("https://www.roblox.com/places/icons/remove-icon")
             .form(&[
                 ("placeId", &start_place_id.to_string()),
                 ("placeIconId", &icon_asset_id.to_string()),
             ]);
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -70,12 +88,13 @@ impl RobloxApi {
         &self,
         experience_id: AssetId,
     ) -> RobloxApiResult<Vec<GetExperienceThumbnailResponse>> {
-        let req = self.client.get(format!(
+        let req = self.client().get(format!(
             "https://games.roblox.com/v1/games/{}/media",
             experience_id
         ));
 
-        Ok(handle_as_json::<GetExperienceThumbnailsResponse>(req)
+        Ok(self
+            .handle_as_json::<GetExperienceThumbnailsResponse>(req)
             .await?
             .data)
     }
@@ -86,14 +105,14 @@ impl RobloxApi {
         new_thumbnail_order: &[AssetId],
     ) -> RobloxApiResult<()> {
         let req = self
-            .client
+            .client()
             .post(format!(
                 "https://develop.roblox.com/v1/universes/{}/thumbnails/order",
                 experience_id
             ))
             .json(&json!({ "thumbnailIds": new_thumbnail_order }));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
@@ -103,12 +122,12 @@ impl RobloxApi {
         experience_id: AssetId,
         thumbnail_id: AssetId,
     ) -> RobloxApiResult<()> {
-        let req = self.client.delete(format!(
+        let req = self.client().delete(format!(
             "https://develop.roblox.com/v1/universes/{}/thumbnails/{}",
             experience_id, thumbnail_id
         ));
 
-        handle(req).await?;
+        self.handle(req).await?;
 
         Ok(())
     }
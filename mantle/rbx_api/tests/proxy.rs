@@ -0,0 +1,119 @@
+//! Proxies are read from environment variables, so these tests run in their own process to avoid
+//! changing the environment of any other tests. `reqwest` also caches the standard proxy variables
+//! the first time they are used, so they are only ever set to a single listener.
+
+use std::{env, sync::Mutex, time::Duration};
+
+use rbx_api::{proxy::ProxyConfig, retry::RetryPolicy, RobloxApi};
+use rbx_auth::RobloxAuth;
+use reqwest::{cookie::Jar, header::HeaderMap};
+use tokio::{
+    io::AsyncReadExt,
+    net::TcpListener,
+    time::{self, timeout},
+};
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+fn auth() -> RobloxAuth {
+    RobloxAuth {
+        jar: Jar::default(),
+        headers: HeaderMap::new(),
+    }
+}
+
+async fn listen() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    (listener, url)
+}
+
+/// Sends a request which the proxy will refuse to tunnel, so it fails without retrying.
+fn send_request(api: RobloxApi) {
+    let api = api.with_retry_policy(RetryPolicy::none());
+    tokio::spawn(async move { api.validate_auth().await });
+}
+
+/// Returns the head of the first request received by the listener, if any is received in time.
+async fn receive_request(listener: &TcpListener, wait: Duration) -> Option<String> {
+    let (mut stream, _) = timeout(wait, listener.accept()).await.ok()?.unwrap();
+    let mut head = Vec::new();
+    let mut buffer = [0; 1024];
+    while !head.ends_with(b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await.unwrap();
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+    Some(String::from_utf8(head).unwrap().to_lowercase())
+}
+
+#[tokio::test]
+async fn mantle_proxy_variables_are_used() {
+    let (listener, url) = listen().await;
+
+    let api = {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::set_var("MANTLE_PROXY_URL", &url);
+        env::set_var("MANTLE_PROXY_USERNAME", "user");
+        env::set_var("MANTLE_PROXY_PASSWORD", "pass");
+        let api = RobloxApi::new(auth());
+        env::remove_var("MANTLE_PROXY_URL");
+        env::remove_var("MANTLE_PROXY_USERNAME");
+        env::remove_var("MANTLE_PROXY_PASSWORD");
+        api.unwrap()
+    };
+    send_request(api);
+
+    let request = receive_request(&listener, Duration::from_secs(10))
+        .await
+        .expect("the request was not sent through the proxy");
+    assert!(request.starts_with("connect users.roblox.com:443"));
+    // "user:pass" in base64
+    assert!(request.contains("proxy-authorization: basic dxnlcjpwyxnz"));
+}
+
+#[tokio::test]
+async fn standard_proxy_variables_are_used_unless_a_proxy_is_provided() {
+    let (environment_listener, environment_url) = listen().await;
+    let (explicit_listener, explicit_url) = listen().await;
+
+    let (default_api, explicit_api) = {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("https_proxy");
+        env::remove_var("NO_PROXY");
+        env::remove_var("no_proxy");
+        env::set_var("HTTPS_PROXY", &environment_url);
+        let default_api = RobloxApi::new(auth());
+        let explicit_api = RobloxApi::new_with_proxy(
+            auth(),
+            Some(ProxyConfig {
+                url: explicit_url,
+                username: None,
+                password: None,
+            }),
+        );
+        (default_api.unwrap(), explicit_api.unwrap())
+    };
+
+    send_request(default_api);
+    let request = receive_request(&environment_listener, Duration::from_secs(10))
+        .await
+        .expect("the request was not sent through the HTTPS_PROXY proxy");
+    assert!(request.starts_with("connect users.roblox.com:443"));
+
+    send_request(explicit_api);
+    let request = receive_request(&explicit_listener, Duration::from_secs(10))
+        .await
+        .expect("the request was not sent through the explicit proxy");
+    assert!(request.starts_with("connect users.roblox.com:443"));
+
+    time::sleep(Duration::from_millis(100)).await;
+    assert!(
+        receive_request(&environment_listener, Duration::ZERO)
+            .await
+            .is_none(),
+        "the explicit proxy did not override HTTPS_PROXY"
+    );
+}
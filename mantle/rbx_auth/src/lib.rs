@@ -1,5 +1,6 @@
-use std::sync::Arc;
+use std::{env, sync::Arc};
 
+use log::info;
 use reqwest::{
     cookie::Jar,
     header::{self, HeaderMap, HeaderValue},
@@ -12,10 +13,12 @@ use url::Url;
 pub enum RobloxAuthError {
     #[error("HTTP client error.")]
     HttpClient(#[from] reqwest::Error),
-    #[error("Unable to find ROBLOSECURITY cookie. Login to Roblox Studio or set the ROBLOSECURITY environment variable.")]
+    #[error("Unable to find ROBLOSECURITY cookie. Checked the explicitly provided cookie, the ROBLOSECURITY environment variable, and the Roblox Studio cookie stores. Login to Roblox Studio or set the ROBLOSECURITY environment variable.")]
     MissingRoblosecurityCookie,
     #[error("Request for CSRF token did not return an X-CSRF-Token header.")]
     MissingCsrfToken,
+    #[error("Challenge continuation values must be valid header values.")]
+    InvalidChallenge,
 }
 
 // Temporary to make the new errors backwards compatible with the String errors throughout the project.
@@ -25,6 +28,27 @@ impl From<RobloxAuthError> for String {
     }
 }
 
+/// A challenge (e.g. two-step verification) which was completed ahead of time. It is sent with
+/// every request so that requests which would otherwise be challenged can proceed headlessly.
+#[derive(Debug, Clone)]
+pub struct ChallengeContinuation {
+    pub id: String,
+    pub challenge_type: String,
+    pub metadata: String,
+}
+
+impl ChallengeContinuation {
+    /// Loads the challenge from the `ROBLOX_CHALLENGE_ID`, `ROBLOX_CHALLENGE_TYPE` and
+    /// `ROBLOX_CHALLENGE_METADATA` environment variables, if they are all set.
+    pub fn from_environment() -> Option<Self> {
+        Some(Self {
+            id: env::var("ROBLOX_CHALLENGE_ID").ok()?,
+            challenge_type: env::var("ROBLOX_CHALLENGE_TYPE").ok()?,
+            metadata: env::var("ROBLOX_CHALLENGE_METADATA").ok()?,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct RobloxAuth {
     pub jar: Jar,
@@ -33,8 +57,21 @@ pub struct RobloxAuth {
 
 impl RobloxAuth {
     pub async fn new() -> Result<Self, RobloxAuthError> {
-        let roblosecurity_cookie =
-            rbx_cookie::get().ok_or(RobloxAuthError::MissingRoblosecurityCookie)?;
+        Self::with_cookie(None).await
+    }
+
+    /// Authenticates with the first ROBLOSECURITY cookie found, in order of precedence:
+    /// 1. The `roblosecurity` override, if provided
+    /// 2. The `ROBLOSECURITY` environment variable
+    /// 3. The Roblox Studio cookie stores
+    pub async fn with_cookie(roblosecurity: Option<String>) -> Result<Self, RobloxAuthError> {
+        let roblosecurity_cookie = match roblosecurity {
+            Some(value) => {
+                info!("Using explicitly provided cookie.");
+                rbx_cookie::format_value(value)
+            }
+            None => rbx_cookie::get().ok_or(RobloxAuthError::MissingRoblosecurityCookie)?,
+        };
 
         let jar = Jar::default();
         let url = "https://roblox.com".parse::<Url>().unwrap();
@@ -43,7 +80,34 @@ impl RobloxAuth {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert("X-CSRF-Token", get_csrf_token(&roblosecurity_cookie).await?);
 
-        Ok(Self { jar, headers })
+        let auth = Self { jar, headers };
+        match ChallengeContinuation::from_environment() {
+            Some(challenge) => {
+                info!("Using challenge continuation from environment variables.");
+                auth.with_challenge_continuation(challenge)
+            }
+            None => Ok(auth),
+        }
+    }
+
+    pub fn with_challenge_continuation(
+        mut self,
+        challenge: ChallengeContinuation,
+    ) -> Result<Self, RobloxAuthError> {
+        let header_value = |value: &str| {
+            HeaderValue::from_str(value).map_err(|_| RobloxAuthError::InvalidChallenge)
+        };
+        self.headers
+            .insert("rblx-challenge-id", header_value(&challenge.id)?);
+        self.headers.insert(
+            "rblx-challenge-type",
+            header_value(&challenge.challenge_type)?,
+        );
+        self.headers.insert(
+            "rblx-challenge-metadata",
+            header_value(&challenge.metadata)?,
+        );
+        Ok(self)
     }
 }
 
@@ -21,6 +21,13 @@ async fn main() {
                 .possible_values(&["table", "json"])
                 .default_value("table"),
         )
+        .arg(
+            Arg::with_name("cookie")
+                .long("cookie")
+                .help("The ROBLOSECURITY cookie to use. Takes precedence over the ROBLOSECURITY environment variable and Roblox Studio.")
+                .value_name("COOKIE")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("verbose")
                 .long("verbose")
@@ -37,14 +44,22 @@ async fn main() {
     };
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level)).init();
 
-    if let Err(err) = run(matches.value_of("format")).await {
+    if let Err(err) = run(
+        matches.value_of("format"),
+        matches.value_of("cookie").map(|v| v.to_owned()),
+    )
+    .await
+    {
         error!("{}", err.to_string());
         std::process::exit(1);
     };
 }
 
-async fn run(format: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
-    let auth = rbx_auth::RobloxAuth::new().await?;
+async fn run(
+    format: Option<&str>,
+    cookie: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let auth = rbx_auth::RobloxAuth::with_cookie(cookie).await?;
 
     let client = reqwest::Client::builder()
         .user_agent("Roblox/WinInet")
@@ -13,14 +13,15 @@ static COOKIE_NAME: &str = ".ROBLOSECURITY";
 
 /// Returns the cookie as a formatted header ready to add to a request
 pub fn get() -> Option<String> {
-    let cookie = get_value()?;
-
-    Some(
-        Cookie::build(COOKIE_NAME, cookie)
-            .domain(".roblox.com")
-            .finish()
-            .to_string(),
-    )
+    Some(format_value(get_value()?))
+}
+
+/// Formats a raw cookie value as a header ready to add to a request
+pub fn format_value(value: String) -> String {
+    Cookie::build(COOKIE_NAME, value)
+        .domain(".roblox.com")
+        .finish()
+        .to_string()
 }
 
 /// Returns the raw cookie value
@@ -7,11 +7,14 @@ use std::{
 
 use rbx_api::{
     experiences::models::{
-        ExperienceAnimationType, ExperienceAvatarType, ExperienceCollisionType,
-        ExperienceConfigurationModel, ExperienceGenre, ExperiencePlayableDevice,
+        ExperienceAgeRating, ExperienceAnimationType, ExperienceAvatarAssetOverride,
+        ExperienceAvatarScales, ExperienceAvatarType, ExperienceCollisionType,
+        ExperienceConfigurationModel, ExperienceGenre, ExperienceJointPositioningType,
+        ExperiencePermissionsModel, ExperiencePlayableDevice, UniverseAvatarBodyColors,
+        UniverseAvatarModel,
     },
     models::{AssetId, AssetTypeId, SocialSlotType},
-    places::models::PlaceConfigurationModel,
+    places::models::{PlaceConfigurationModel, PlaceFileFormat},
 };
 use rusoto_core::Region;
 use schemars::JsonSchema;
@@ -19,6 +22,8 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 use yansi::Paint;
 
+use super::roblox_resource_manager::ProductDeleteBehavior;
+
 #[derive(JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct Config {
@@ -345,6 +350,22 @@ pub enum TargetOverridesConfig {
 #[derive(JsonSchema, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ExperienceTargetConfig {
+    /// The ID of an existing experience to deploy to instead of creating a new one. Since each
+    /// environment needs its own experience, this is usually set in an environment's
+    /// [`targetOverrides`](#environments--targetoverrides).
+    ///
+    /// Once an environment has been deployed, its experience cannot be switched to another one
+    /// by changing this property. Destroy the environment with `mantle destroy` and deploy it
+    /// again instead.
+    ///
+    /// ```yml title="Example"
+    /// environments:
+    ///   - label: prod
+    ///     targetOverrides:
+    ///       id: 1234567890
+    /// ```
+    pub id: Option<AssetId>,
+
     /// The Experience's Roblox configuration.
     ///
     /// ```yml title="Example"
@@ -368,11 +389,15 @@ pub struct ExperienceTargetConfig {
     /// [`description`](#target-experience-places-label-configuration-description)
     /// properties of the experience's start place
     /// :::
+    ///
+    /// Properties are only sent to Roblox when they are specified, so removing
+    /// one from the config will leave the experience's current setting
+    /// unchanged.
     pub configuration: Option<ExperienceTargetConfigurationConfig>,
 
     /// The experience's places. There must be at least one place supplied with
-    /// the label `'start'`, which will be used as the start place for the
-    /// experience.
+    /// the label `'start'` (or the label set by [`startPlace`](#target-experience-startplace)),
+    /// which will be used as the start place for the experience.
     ///
     /// ```yml title="Example"
     /// target:
@@ -391,12 +416,55 @@ pub struct ExperienceTargetConfig {
     /// ```
     pub places: Option<HashMap<String, PlaceTargetConfig>>,
 
-    /// A file path to an image that will be used as the experience's icon.
+    /// default('start')
+    ///
+    /// The label of the place in [`places`](#target-experience-places) to use as the experience's
+    /// start place. Changing it makes another place the start place without recreating the
+    /// experience, and the previous start place is kept as a regular place. The new start place
+    /// must already have been deployed, so add it in one deployment and make it the start place in
+    /// the next.
+    pub start_place: Option<String>,
+
+    /// A file path to an image that will be used as the experience's icon. Cannot be used together
+    /// with [`iconUrl`](#target-experience-iconurl).
     pub icon: Option<String>,
 
+    /// A URL of a PNG or JPEG image that will be used as the experience's icon. The image is
+    /// downloaded each time Mantle builds the desired state, so it is only uploaded again when its
+    /// contents change. Images larger than 20 MB are rejected. Cannot be used together with
+    /// [`icon`](#target-experience-icon).
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     iconUrl: https://cdn.example.com/brand/game-icon.png
+    /// ```
+    pub icon_url: Option<Url>,
+
     /// An array of file paths to images that will be used as the experience's thumbnails. The order
     /// used here will be the order they appear on the Roblox webpage.
-    pub thumbnails: Option<Vec<String>>,
+    ///
+    /// To use an existing asset (such as a video) as a thumbnail instead of uploading a file, provide
+    /// its `assetId`. To upload a PNG or JPEG image from a URL (such as a CDN) instead of a file,
+    /// provide its `url`; the image is downloaded each time Mantle builds the desired state and
+    /// images larger than 20 MB are rejected. Each thumbnail must have exactly one of `file`, `url`,
+    /// or `assetId`.
+    ///
+    /// The first thumbnail is the experience's primary thumbnail, which is shown most prominently. To
+    /// make another thumbnail the primary thumbnail without reordering the list, set `primary: true`
+    /// on it. At most one thumbnail can be primary.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     thumbnails:
+    ///       - marketing/game-thumbnail-default.png
+    ///       - assetId: 1818
+    ///       - url: https://cdn.example.com/brand/game-thumbnail.png
+    ///       - file: marketing/game-thumbnail-event.png
+    ///         primary: true
+    /// ```
+    pub thumbnails: Option<Vec<ThumbnailTargetConfig>>,
 
     /// A list of social links that will appear on the experience's webpage.
     ///
@@ -428,13 +496,22 @@ pub struct ExperienceTargetConfig {
     /// ```
     ///
     /// Because Roblox does not offer any way to delete developer products, when a product is "deleted"
-    /// by Mantle, it is updated in the following ways:
-    ///
-    /// 1. Its description is updated to: `Name: <name>\nDescription:\n<description>`
-    /// 2. Its name is updated to `zzz_Deprecated(<date-time>)` where `<date-time>` is the current
-    ///    date-time in `YYYY-MM-DD hh::mm::ss.ns` format.
+    /// by Mantle, it is retired according to [`productDeleteBehavior`](#target-experience-productdeletebehavior).
     pub products: Option<HashMap<String, ProductTargetConifg>>,
 
+    /// default('deactivate')
+    ///
+    /// How developer products removed from [`products`](#target-experience-products) are retired.
+    ///
+    /// | Value          | Description                                                                                                                                                      |
+    /// |----------------|------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+    /// | `'deactivate'` | The product is taken off sale and otherwise left unchanged. If Roblox does not support this, the product is renamed instead.                                      |
+    /// | `'rename'`     | The product's name is updated to `zzz_Deprecated(<date-time>)` where `<date-time>` is the current date-time in `YYYY-MM-DD hh::mm::ss.ns` format, and its description and price are cleared. This matches how older versions of Mantle retired products. |
+    ///
+    /// Because deactivated products keep their names, a product with the same name cannot be
+    /// created until the deactivated product is renamed.
+    pub product_delete_behavior: Option<ProductDeleteBehaviorTargetConfig>,
+
     /// Passes that can be purchased within your experience for Robux.
     ///
     /// ```yml title="Example"
@@ -456,6 +533,25 @@ pub struct ExperienceTargetConfig {
     ///    in `YYYY-MM-DD hh::mm::ss.ns` format.
     pub passes: Option<HashMap<String, PassTargetConfig>>,
 
+    /// Subscriptions that can be purchased within your experience for recurring payments.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     subscriptions:
+    ///       vip:
+    ///         name: VIP
+    ///         description: Get VIP perks every month!
+    ///         icon: subscriptions/vip.png
+    ///         price: 100
+    ///         period: monthly
+    /// ```
+    ///
+    /// Because Roblox does not allow subscriptions to be deleted, when a subscription is "deleted"
+    /// by Mantle, it is deactivated instead. Roblox will not allow subscriptions with active
+    /// subscribers to be deactivated.
+    pub subscriptions: Option<HashMap<String, SubscriptionTargetConfig>>,
+
     /// Badges that can be awarded within your experience.
     ///
     /// ```yml title="Example"
@@ -546,6 +642,84 @@ pub struct ExperienceTargetConfig {
     ///         content: '{displayName} is inviting you to join {experienceName}!'
     /// ```
     pub notifications: Option<HashMap<String, NotificationTargetConfig>>,
+
+    /// Collaborators who can play or edit your experience. Each collaborator is either a user or a
+    /// group role. When deploying, Mantle grants any permissions which are missing and removes any
+    /// permissions which are not listed, except for the experience owner's.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     collaborators:
+    ///       - user: 12345678
+    ///         access: edit
+    ///       - groupRole: 87654321
+    ///         access: play
+    /// ```
+    pub collaborators: Option<Vec<CollaboratorTargetConfig>>,
+
+    /// Localized titles and descriptions for your experience's store page, keyed by locale code
+    /// (e.g. `fr`, `es`, `zh-hans`). Only locales supported by Roblox may be used.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     locales:
+    ///       fr:
+    ///         title: Mon expérience
+    ///         description: Une expérience incroyable
+    /// ```
+    pub locales: Option<HashMap<String, LocaleTargetConfig>>,
+
+    /// Detailed avatar settings for your experience. Only the settings which are specified are
+    /// changed. Scale constraints and body part or clothing overrides are set with the
+    /// `avatarScaleConstraints` and `avatarAssetOverrides` properties of the experience's
+    /// `configuration`.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     avatar:
+    ///       avatarType: playerChoice
+    ///       bodyColors:
+    ///         head: 24
+    ///         torso: 23
+    ///       animations:
+    ///         run: 913376220
+    ///         walk: 913402848
+    /// ```
+    pub avatar: Option<AvatarTargetConfig>,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CollaboratorTargetConfig {
+    /// The ID of the user to grant access to. Either `user` or `groupRole` must be set.
+    pub user: Option<AssetId>,
+
+    /// The ID of the group role (roleset) to grant access to. Either `user` or `groupRole` must be
+    /// set.
+    pub group_role: Option<AssetId>,
+
+    /// The access to grant.
+    pub access: CollaboratorAccessTargetConfig,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum CollaboratorAccessTargetConfig {
+    Play,
+    Edit,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LocaleTargetConfig {
+    /// The localized title of the experience.
+    pub title: String,
+
+    /// The localized description of the experience.
+    pub description: String,
 }
 
 #[derive(JsonSchema, Serialize, Deserialize, Clone)]
@@ -616,7 +790,7 @@ pub enum PrivateServersTargetConfig {
     Price(u32),
 }
 
-#[derive(JsonSchema, Serialize, Deserialize, Clone)]
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum AvatarTypeTargetConfig {
     R6,
@@ -624,6 +798,122 @@ pub enum AvatarTypeTargetConfig {
     PlayerChoice,
 }
 
+impl From<&AvatarTypeTargetConfig> for ExperienceAvatarType {
+    fn from(config: &AvatarTypeTargetConfig) -> Self {
+        match config {
+            AvatarTypeTargetConfig::R6 => ExperienceAvatarType::MorphToR6,
+            AvatarTypeTargetConfig::R15 => ExperienceAvatarType::MorphToR15,
+            AvatarTypeTargetConfig::PlayerChoice => ExperienceAvatarType::PlayerChoice,
+        }
+    }
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AvatarTargetConfig {
+    /// The types of avatars that players can use in this experience. Must match the
+    /// `avatarType` of the experience's `configuration` if both are set. Animation overrides can
+    /// only be used when this is `'playerChoice'`.
+    pub avatar_type: AvatarTypeTargetConfig,
+
+    /// The BrickColor IDs to apply to each of the avatar's body parts.
+    pub body_colors: Option<AvatarBodyColorsTargetConfig>,
+
+    /// The animation asset IDs to override the avatar's animations.
+    pub animations: Option<AvatarAnimationsTargetConfig>,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AvatarBodyColorsTargetConfig {
+    /// The BrickColor ID of the avatar's head.
+    pub head: Option<u32>,
+
+    /// The BrickColor ID of the avatar's torso.
+    pub torso: Option<u32>,
+
+    /// The BrickColor ID of the avatar's left arm.
+    pub left_arm: Option<u32>,
+
+    /// The BrickColor ID of the avatar's right arm.
+    pub right_arm: Option<u32>,
+
+    /// The BrickColor ID of the avatar's left leg.
+    pub left_leg: Option<u32>,
+
+    /// The BrickColor ID of the avatar's right leg.
+    pub right_leg: Option<u32>,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AvatarAnimationsTargetConfig {
+    /// The asset ID to override the avatar's climb animation.
+    pub climb: Option<AssetId>,
+
+    /// The asset ID to override the avatar's fall animation.
+    pub fall: Option<AssetId>,
+
+    /// The asset ID to override the avatar's idle animation.
+    pub idle: Option<AssetId>,
+
+    /// The asset ID to override the avatar's jump animation.
+    pub jump: Option<AssetId>,
+
+    /// The asset ID to override the avatar's run animation.
+    pub run: Option<AssetId>,
+
+    /// The asset ID to override the avatar's swim animation.
+    pub swim: Option<AssetId>,
+
+    /// The asset ID to override the avatar's walk animation.
+    pub walk: Option<AssetId>,
+}
+
+impl From<&AvatarTargetConfig> for UniverseAvatarModel {
+    fn from(config: &AvatarTargetConfig) -> Self {
+        let universe_avatar_body_colors =
+            config
+                .body_colors
+                .map(|body_colors| UniverseAvatarBodyColors {
+                    head_color_id: body_colors.head,
+                    torso_color_id: body_colors.torso,
+                    left_arm_color_id: body_colors.left_arm,
+                    right_arm_color_id: body_colors.right_arm,
+                    left_leg_color_id: body_colors.left_leg,
+                    right_leg_color_id: body_colors.right_leg,
+                });
+
+        let universe_avatar_asset_overrides = match &config.animations {
+            Some(animations) => [
+                (AssetTypeId::ClimbAnimation, animations.climb),
+                (AssetTypeId::FallAnimation, animations.fall),
+                (AssetTypeId::IdleAnimation, animations.idle),
+                (AssetTypeId::JumpAnimation, animations.jump),
+                (AssetTypeId::RunAnimation, animations.run),
+                (AssetTypeId::SwimAnimation, animations.swim),
+                (AssetTypeId::WalkAnimation, animations.walk),
+            ]
+            .into_iter()
+            .filter_map(|(asset_type_id, asset_id)| {
+                asset_id.map(|asset_id| ExperienceAvatarAssetOverride {
+                    asset_type_id,
+                    is_player_choice: false,
+                    asset_id: Some(asset_id),
+                })
+            })
+            .collect(),
+            None => Vec::new(),
+        };
+
+        UniverseAvatarModel {
+            universe_avatar_type: Some((&config.avatar_type).into()),
+            universe_avatar_body_colors,
+            universe_avatar_asset_overrides,
+        }
+    }
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum PlayableDeviceTargetConfig {
@@ -635,6 +925,15 @@ pub enum PlayableDeviceTargetConfig {
     VR,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum AgeRatingTargetConfig {
+    Minimal,
+    Mild,
+    Moderate,
+    Restricted,
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum AnimationTypeTargetConfig {
@@ -642,6 +941,13 @@ pub enum AnimationTypeTargetConfig {
     PlayerChoice,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum JointPositioningTypeTargetConfig {
+    Standard,
+    ArtificialPhysics,
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum CollisionTypeTargetConfig {
@@ -712,6 +1018,22 @@ pub struct AvatarAssetOverridesTargetConfig {
     pub pants: Option<AssetId>,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum ProductDeleteBehaviorTargetConfig {
+    Deactivate,
+    Rename,
+}
+
+impl From<ProductDeleteBehaviorTargetConfig> for ProductDeleteBehavior {
+    fn from(config: ProductDeleteBehaviorTargetConfig) -> Self {
+        match config {
+            ProductDeleteBehaviorTargetConfig::Deactivate => ProductDeleteBehavior::Deactivate,
+            ProductDeleteBehaviorTargetConfig::Rename => ProductDeleteBehavior::Rename,
+        }
+    }
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ProductTargetConifg {
@@ -724,11 +1046,48 @@ pub struct ProductTargetConifg {
     pub description: Option<String>,
 
     /// A file path to an image to use as the product's icon on the Roblox website and in the
-    /// experience.
+    /// experience. Cannot be used together with
+    /// [`iconAssetId`](#target-experience-products-label-iconassetid).
     pub icon: Option<String>,
 
+    /// The ID of an existing image asset to use as the product's icon instead of uploading an
+    /// [`icon`](#target-experience-products-label-icon). Useful when several products share the
+    /// same icon.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     products:
+    ///       fiftyGold:
+    ///         name: 50 Gold
+    ///         price: 25
+    ///         iconAssetId: 1234567890
+    ///       hundredGold:
+    ///         name: 100 Gold
+    ///         price: 45
+    ///         iconAssetId: 1234567890
+    /// ```
+    pub icon_asset_id: Option<AssetId>,
+
     /// The price of the developer product in Robux.
     pub price: u32,
+
+    /// Prices in Robux for specific regions, keyed by region code (an uppercase ISO 3166-1
+    /// alpha-2 country code, e.g. `BR`). Players in other regions pay `price`. If not specified,
+    /// the product's regional prices are left unchanged.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     products:
+    ///       fiftyGold:
+    ///         name: 50 Gold
+    ///         price: 25
+    ///         regionalPrices:
+    ///           BR: 15
+    ///           IN: 10
+    /// ```
+    pub regional_prices: Option<HashMap<String, u32>>,
 }
 
 #[derive(JsonSchema, Serialize, Deserialize, Clone)]
@@ -750,6 +1109,36 @@ pub struct PassTargetConfig {
     pub price: Option<u32>,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SubscriptionTargetConfig {
+    /// The display name of the subscription on the Roblox website and in the experience.
+    pub name: String,
+
+    /// default('')
+    ///
+    /// The description of the subscription on the Roblox website and in the experience.
+    pub description: Option<String>,
+
+    /// A file path to an image to use as the subscription's icon on the Roblox website and in the
+    /// experience.
+    pub icon: String,
+
+    /// The price of the subscription in Robux.
+    pub price: u32,
+
+    /// default('monthly')
+    ///
+    /// How often subscribers will be charged for the subscription.
+    pub period: Option<SubscriptionPeriodTargetConfig>,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionPeriodTargetConfig {
+    Monthly,
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct BadgeTargetConfig {
@@ -771,6 +1160,19 @@ pub struct BadgeTargetConfig {
     pub enabled: Option<bool>,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", untagged)]
+pub enum ThumbnailTargetConfig {
+    File(String),
+    #[serde(rename_all = "camelCase")]
+    Options {
+        file: Option<String>,
+        url: Option<Url>,
+        asset_id: Option<AssetId>,
+        primary: Option<bool>,
+    },
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum AssetTargetConfig {
@@ -788,14 +1190,33 @@ pub struct SpatialVoiceTargetConfig {
 #[derive(JsonSchema, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ExperienceTargetConfigurationConfig {
-    /// default('all')
-    ///
-    /// The experience's genre.
+    /// The experience's genre. Unknown genres are rejected when the config is loaded. If not
+    /// specified, the experience's current genre will not be changed.
+    ///
+    /// | Value           | Roblox genre   |
+    /// | :-------------- | :------------- |
+    /// | `'all'`         | All            |
+    /// | `'adventure'`   | Adventure      |
+    /// | `'building'`    | Building       |
+    /// | `'comedy'`      | Comedy         |
+    /// | `'fighting'`    | Fighting       |
+    /// | `'fps'`         | FPS            |
+    /// | `'horror'`      | Horror         |
+    /// | `'medieval'`    | Medieval       |
+    /// | `'military'`    | Military       |
+    /// | `'naval'`       | Naval          |
+    /// | `'rpg'`         | RPG            |
+    /// | `'sciFi'`       | Sci-Fi         |
+    /// | `'sports'`      | Sports         |
+    /// | `'townAndCity'` | Town and City  |
+    /// | `'western'`     | Western        |
+    ///
+    /// Subgenres are not supported, since they cannot be set through the experience configuration
+    /// API which Mantle uses.
     pub genre: Option<GenreTargetConfig>,
 
-    /// default(['computer', 'phone', 'tablet'])
-    ///
-    /// The devices that the experience can be played on.
+    /// The devices that the experience can be played on. If not specified, the experience's
+    /// current devices will not be changed.
     pub playable_devices: Option<Vec<PlayableDeviceTargetConfig>>,
 
     /// default('private')
@@ -807,15 +1228,33 @@ pub struct ExperienceTargetConfigurationConfig {
     /// | `'public'`  | The experience will be playable by all Roblox users.                                        |
     /// | `'private'` | The experience will only be playable by the authorized user.                                |
     /// | `'friends'` | The experience will only be playable to the authorized user and that user's Roblox friends. |
+    ///
+    /// Unless [`active`](#target-experience-configuration-active) is set, `'private'` experiences
+    /// are deactivated and `'public'` and `'friends'` experiences are activated.
     pub playability: Option<PlayabilityTargetConfig>,
 
-    /// default('disabled')
+    /// Whether or not the experience is active. If not specified, the experience is active unless
+    /// its [`playability`](#target-experience-configuration-playability) is `'private'`. Setting
+    /// this to `false` takes a `'public'` or `'friends'` experience offline without changing its
+    /// playability, so it will have the same playability once it is activated again. A `'private'`
+    /// experience cannot be active.
+    ///
+    /// ```yml title="Inactive Public Experience Example"
+    /// target:
+    ///   experience:
+    ///     configuration:
+    ///       playability: public
+    ///       active: false
+    /// ```
+    pub active: Option<bool>,
+
     /// skip_properties()
     ///
     /// Determines whether or not paid access is be enabled, and if it is, how
     /// much it costs. This should not be enabled when
     /// [`privateServers`](#target-experience-configuration-privateservers) are
-    /// also enabled as they are incompatible.
+    /// also enabled as they are incompatible. If not specified, the
+    /// experience's current paid access will not be changed.
     ///
     /// | Value            | Description                                                                                      |
     /// |------------------|--------------------------------------------------------------------------------------------------|
@@ -829,16 +1268,15 @@ pub struct ExperienceTargetConfigurationConfig {
     ///       paidAccess:
     ///         price: 100
     /// ```
-    #[serde(default)]
-    pub paid_access: PaidAccessTargetConfig,
+    pub paid_access: Option<PaidAccessTargetConfig>,
 
-    /// default('disabled')
     /// skip_properties()
     ///
     /// Determines whether or not private servers are enabled, and if they are,
     /// how much they cost. This should not be enabled when
     /// [`paidAccess`](#target-experience-configuration-paidaccess) is also
-    /// enabled as they are incompatible.
+    /// enabled as they are incompatible. If not specified, the experience's
+    /// current private servers will not be changed.
     ///
     /// | Value            | Description                                                                 |
     /// |------------------|-----------------------------------------------------------------------------|
@@ -860,44 +1298,50 @@ pub struct ExperienceTargetConfigurationConfig {
     ///       privateServers:
     ///         price: 100
     /// ```
-    #[serde(default)]
-    pub private_servers: PrivateServersTargetConfig,
-
-    /// default(false)
-    ///
-    /// Whether or not studio should be able to use Roblox APIs for this place.
+    pub private_servers: Option<PrivateServersTargetConfig>,
+
+    /// Whether or not private server owners can share links which let other players join their
+    /// servers. If not specified, the experience's current setting will not be changed. Can only
+    /// be enabled when [`privateServers`](#target-experience-configuration-privateservers) are
+    /// enabled.
+    pub private_server_link_sharing: Option<bool>,
+
+    /// Whether or not private servers can only be bought by players with an active subscription
+    /// to the experience. If not specified, the experience's current setting will not be changed.
+    /// Can only be enabled when [`privateServers`](#target-experience-configuration-privateservers)
+    /// are enabled.
+    pub private_servers_subscribers_only: Option<bool>,
+
+    /// Whether or not studio should be able to use Roblox APIs for this place. If not specified,
+    /// the experience's current setting will not be changed.
     pub enable_studio_access_to_apis: Option<bool>,
 
-    /// default(false)
-    ///
-    /// Whether or not this experience should allow third-party sales.
+    /// Whether or not this experience should allow third-party sales. If not specified, the
+    /// experience's current setting will not be changed.
     pub allow_third_party_sales: Option<bool>,
 
-    /// default(false)
-    ///
-    /// Whether or not this experience should allow third-party teleports.
+    /// Whether or not this experience should allow third-party teleports. If not specified, the
+    /// experience's current setting will not be changed.
     pub allow_third_party_teleports: Option<bool>,
 
-    /// default('r15')
-    ///
-    /// The types of avatars that players can use in this experience.
+    /// The types of avatars that players can use in this experience. If not specified, the
+    /// experience's current setting will not be changed.
     pub avatar_type: Option<AvatarTypeTargetConfig>,
 
-    /// default('playerChoice')
-    ///
-    /// The type of avatar animation that players can use in this experience.
+    /// The type of avatar animation that players can use in this experience. If not specified,
+    /// the experience's current setting will not be changed.
     pub avatar_animation_type: Option<AnimationTypeTargetConfig>,
 
-    /// default('outerBox')
-    ///
-    /// The type of avatar collision that players can use in this experience.
+    /// The type of avatar collision that players can use in this experience. If not specified,
+    /// the experience's current setting will not be changed.
     pub avatar_collision_type: Option<CollisionTypeTargetConfig>,
 
     /// skip_properties()
     ///
     /// The scale constraints to apply to player avatars in the experience.
-    /// Defaults to Roblox's defaults. Each entry may include a `min`, `max`, or
-    /// both. If one is excluded, the default will be used.
+    /// If not specified, the experience's current constraints will not be
+    /// changed. Each entry may include a `min`, `max`, or both. If one is
+    /// excluded, Roblox's default will be used.
     ///
     /// Supported properties: `bodyType`, `head`, `height`, `proportions`,
     /// `width`.
@@ -920,7 +1364,8 @@ pub struct ExperienceTargetConfigurationConfig {
     /// skip_properties()
     ///
     /// The asset overrides to apply to player avatars in the experience.
-    /// Defaults to Roblox's defaults.
+    /// If not specified, the experience's current overrides will not be
+    /// changed. Assets which are not overridden can be chosen by players.
     ///
     /// Supported properties: `face`, `head`, `leftArm`, `leftLeg`, `rightArm`,
     /// `rightLeg`, `torso`, `tshirt`, `shirt`, `pants`
@@ -935,13 +1380,63 @@ pub struct ExperienceTargetConfigurationConfig {
     ///         pants: 5611120855
     /// ```
     pub avatar_asset_overrides: Option<AvatarAssetOverridesTargetConfig>,
+
+    /// The content maturity rating of the experience. If not specified, the experience's current
+    /// rating will not be changed.
+    ///
+    /// | Value          | Description                                                          |
+    /// |----------------|----------------------------------------------------------------------|
+    /// | `'minimal'`    | The experience may contain occasional mild violence or light fear.   |
+    /// | `'mild'`       | The experience may contain repeated mild violence or crude humor.    |
+    /// | `'moderate'`   | The experience may contain moderate violence or moderate fear.       |
+    /// | `'restricted'` | The experience may contain strong violence and is only for ages 17+. |
+    ///
+    /// The rating also determines which ages the experience is available to.
+    pub age_rating: Option<AgeRatingTargetConfig>,
+
+    /// Regions where the experience will not be available, as region codes (uppercase ISO 3166-1
+    /// alpha-2 country codes, e.g. `DE`). If not specified, the experience's current availability
+    /// will not be changed. Set it to an empty list to make the experience available in all
+    /// regions.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     configuration:
+    ///       unavailableRegions:
+    ///         - BE
+    ///         - NL
+    /// ```
+    pub unavailable_regions: Option<Vec<String>>,
+
+    /// How avatar joints are positioned in the experience. If not specified, the experience's
+    /// current setting will not be changed.
+    ///
+    /// | Value                 | Description                                                 |
+    /// |-----------------------|-------------------------------------------------------------|
+    /// | `'standard'`          | Avatar joints will be positioned by Roblox's default rules. |
+    /// | `'artificialPhysics'` | Avatar joints will be positioned with artificial physics.   |
+    pub avatar_joint_positioning_type: Option<JointPositioningTypeTargetConfig>,
+
+    /// Whether or not scripts can use the mesh and texture APIs in this experience. If not
+    /// specified, the experience's current setting will not be changed.
+    pub allow_mesh_texture_api_access: Option<bool>,
+
+    /// Whether or not immersive ads can be shown in this experience. If not specified, the
+    /// experience's current setting will not be changed.
+    pub enable_immersive_ads: Option<bool>,
+
+    /// Whether or not players can be offered rewarded video ads in this experience. If not
+    /// specified, the experience's current setting will not be changed.
+    pub enable_rewarded_video_ads: Option<bool>,
 }
 
+/// Only maps the properties which are specified in the config so that any others are left
+/// unchanged.
 impl From<&ExperienceTargetConfigurationConfig> for ExperienceConfigurationModel {
     fn from(config: &ExperienceTargetConfigurationConfig) -> Self {
-        let mut model = ExperienceConfigurationModel::default();
-        if let Some(genre) = &config.genre {
-            model.genre = match genre {
+        let mut model = ExperienceConfigurationModel {
+            genre: config.genre.as_ref().map(|genre| match genre {
                 GenreTargetConfig::All => ExperienceGenre::All,
                 GenreTargetConfig::Adventure => ExperienceGenre::Adventure,
                 GenreTargetConfig::Building => ExperienceGenre::Tutorial,
@@ -957,102 +1452,132 @@ impl From<&ExperienceTargetConfigurationConfig> for ExperienceConfigurationModel
                 GenreTargetConfig::Sports => ExperienceGenre::Sports,
                 GenreTargetConfig::TownAndCity => ExperienceGenre::TownAndCity,
                 GenreTargetConfig::Western => ExperienceGenre::WildWest,
-            }
-        }
-        if let Some(playable_devices) = &config.playable_devices {
-            model.playable_devices = playable_devices
-                .iter()
-                .map(|device| match device {
-                    PlayableDeviceTargetConfig::Computer => ExperiencePlayableDevice::Computer,
-                    PlayableDeviceTargetConfig::Phone => ExperiencePlayableDevice::Phone,
-                    PlayableDeviceTargetConfig::Tablet => ExperiencePlayableDevice::Tablet,
-                    PlayableDeviceTargetConfig::Console => ExperiencePlayableDevice::Console,
-                    PlayableDeviceTargetConfig::VR => ExperiencePlayableDevice::VR,
-                })
-                .collect();
-        }
-        if let Some(playability) = &config.playability {
-            model.is_friends_only = match playability {
-                PlayabilityTargetConfig::Friends => Some(true),
-                PlayabilityTargetConfig::Public => Some(false),
-                PlayabilityTargetConfig::Private => None,
-            }
-        }
-        model.is_for_sale = !matches!(config.paid_access, PaidAccessTargetConfig::Disabled);
-        model.price = match config.paid_access {
-            PaidAccessTargetConfig::Price(price) => Some(price),
-            _ => None,
-        };
-        model.allow_private_servers =
-            !matches!(config.private_servers, PrivateServersTargetConfig::Disabled);
-        model.private_server_price = match config.private_servers {
-            PrivateServersTargetConfig::Free => Some(0),
-            PrivateServersTargetConfig::Price(price) => Some(price),
-            _ => None,
+            }),
+            playable_devices: config.playable_devices.as_ref().map(|playable_devices| {
+                playable_devices
+                    .iter()
+                    .map(|device| match device {
+                        PlayableDeviceTargetConfig::Computer => ExperiencePlayableDevice::Computer,
+                        PlayableDeviceTargetConfig::Phone => ExperiencePlayableDevice::Phone,
+                        PlayableDeviceTargetConfig::Tablet => ExperiencePlayableDevice::Tablet,
+                        PlayableDeviceTargetConfig::Console => ExperiencePlayableDevice::Console,
+                        PlayableDeviceTargetConfig::VR => ExperiencePlayableDevice::VR,
+                    })
+                    .collect()
+            }),
+            is_friends_only: config
+                .playability
+                .and_then(|playability| match playability {
+                    PlayabilityTargetConfig::Friends => Some(true),
+                    PlayabilityTargetConfig::Public => Some(false),
+                    PlayabilityTargetConfig::Private => None,
+                }),
+            studio_access_to_apis_allowed: config.enable_studio_access_to_apis,
+            universe_avatar_type: config
+                .avatar_type
+                .as_ref()
+                .map(|avatar_type| avatar_type.into()),
+            universe_animation_type: config.avatar_animation_type.as_ref().map(
+                |avatar_animation_type| match avatar_animation_type {
+                    AnimationTypeTargetConfig::Standard => ExperienceAnimationType::Standard,
+                    AnimationTypeTargetConfig::PlayerChoice => {
+                        ExperienceAnimationType::PlayerChoice
+                    }
+                },
+            ),
+            universe_collision_type: config.avatar_collision_type.as_ref().map(
+                |avatar_collision_type| match avatar_collision_type {
+                    CollisionTypeTargetConfig::OuterBox => ExperienceCollisionType::OuterBox,
+                    CollisionTypeTargetConfig::InnerBox => ExperienceCollisionType::InnerBox,
+                },
+            ),
+            age_rating: config.age_rating.map(|age_rating| match age_rating {
+                AgeRatingTargetConfig::Minimal => ExperienceAgeRating::Minimal,
+                AgeRatingTargetConfig::Mild => ExperienceAgeRating::Mild,
+                AgeRatingTargetConfig::Moderate => ExperienceAgeRating::Moderate,
+                AgeRatingTargetConfig::Restricted => ExperienceAgeRating::Restricted,
+            }),
+            universe_joint_positioning_type: config.avatar_joint_positioning_type.map(
+                |joint_positioning_type| match joint_positioning_type {
+                    JointPositioningTypeTargetConfig::Standard => {
+                        ExperienceJointPositioningType::Standard
+                    }
+                    JointPositioningTypeTargetConfig::ArtificialPhysics => {
+                        ExperienceJointPositioningType::ArtificialPhysics
+                    }
+                },
+            ),
+            is_mesh_texture_api_access_allowed: config.allow_mesh_texture_api_access,
+            is_immersive_ads_enabled: config.enable_immersive_ads,
+            is_rewarded_video_ads_enabled: config.enable_rewarded_video_ads,
+            unavailable_regions: config.unavailable_regions.clone(),
+            ..Default::default()
         };
-        if let Some(enable_studio_access_to_apis) = config.enable_studio_access_to_apis {
-            model.studio_access_to_apis_allowed = enable_studio_access_to_apis;
-        }
-        if let Some(allow_third_party_sales) = config.allow_third_party_sales {
-            model.permissions.is_third_party_purchase_allowed = allow_third_party_sales;
-        }
-        if let Some(allow_third_party_teleports) = config.allow_third_party_teleports {
-            model.permissions.is_third_party_teleport_allowed = allow_third_party_teleports;
-        }
-        if let Some(avatar_type) = &config.avatar_type {
-            model.universe_avatar_type = match avatar_type {
-                AvatarTypeTargetConfig::R6 => ExperienceAvatarType::MorphToR6,
-                AvatarTypeTargetConfig::R15 => ExperienceAvatarType::MorphToR15,
-                AvatarTypeTargetConfig::PlayerChoice => ExperienceAvatarType::PlayerChoice,
-            }
+        if let Some(paid_access) = &config.paid_access {
+            model.is_for_sale = Some(!matches!(paid_access, PaidAccessTargetConfig::Disabled));
+            model.price = match paid_access {
+                PaidAccessTargetConfig::Price(price) => Some(*price),
+                _ => None,
+            };
         }
-        if let Some(avatar_animation_type) = &config.avatar_animation_type {
-            model.universe_animation_type = match avatar_animation_type {
-                AnimationTypeTargetConfig::Standard => ExperienceAnimationType::Standard,
-                AnimationTypeTargetConfig::PlayerChoice => ExperienceAnimationType::PlayerChoice,
-            }
+        if let Some(private_servers) = &config.private_servers {
+            model.allow_private_servers = Some(!matches!(
+                private_servers,
+                PrivateServersTargetConfig::Disabled
+            ));
+            model.private_server_price = match private_servers {
+                PrivateServersTargetConfig::Free => Some(0),
+                PrivateServersTargetConfig::Price(price) => Some(*price),
+                _ => None,
+            };
         }
-        if let Some(avatar_collision_type) = &config.avatar_collision_type {
-            model.universe_collision_type = match avatar_collision_type {
-                CollisionTypeTargetConfig::OuterBox => ExperienceCollisionType::OuterBox,
-                CollisionTypeTargetConfig::InnerBox => ExperienceCollisionType::InnerBox,
-            }
+        if config.allow_third_party_sales.is_some() || config.allow_third_party_teleports.is_some()
+        {
+            model.permissions = Some(ExperiencePermissionsModel {
+                is_third_party_purchase_allowed: config.allow_third_party_sales,
+                is_third_party_teleport_allowed: config.allow_third_party_teleports,
+            });
         }
         if let Some(constraints) = &config.avatar_scale_constraints {
+            let mut min_scales = ExperienceAvatarScales::default_min();
+            let mut max_scales = ExperienceAvatarScales::default_max();
             if let Some(height) = constraints.height.and_then(|c| c.min) {
-                model.universe_avatar_min_scales.height = height.to_string();
+                min_scales.height = height.to_string();
             }
             if let Some(width) = constraints.width.and_then(|c| c.min) {
-                model.universe_avatar_min_scales.width = width.to_string();
+                min_scales.width = width.to_string();
             }
             if let Some(head) = constraints.head.and_then(|c| c.min) {
-                model.universe_avatar_min_scales.head = head.to_string();
+                min_scales.head = head.to_string();
             }
             if let Some(body_type) = constraints.body_type.and_then(|c| c.min) {
-                model.universe_avatar_min_scales.body_type = body_type.to_string();
+                min_scales.body_type = body_type.to_string();
             }
             if let Some(proportions) = constraints.proportions.and_then(|c| c.min) {
-                model.universe_avatar_min_scales.proportion = proportions.to_string();
+                min_scales.proportion = proportions.to_string();
             }
 
             if let Some(height) = constraints.height.and_then(|c| c.max) {
-                model.universe_avatar_max_scales.height = height.to_string();
+                max_scales.height = height.to_string();
             }
             if let Some(width) = constraints.width.and_then(|c| c.max) {
-                model.universe_avatar_max_scales.width = width.to_string();
+                max_scales.width = width.to_string();
             }
             if let Some(head) = constraints.head.and_then(|c| c.max) {
-                model.universe_avatar_max_scales.head = head.to_string();
+                max_scales.head = head.to_string();
             }
             if let Some(body_type) = constraints.body_type.and_then(|c| c.max) {
-                model.universe_avatar_max_scales.body_type = body_type.to_string();
+                max_scales.body_type = body_type.to_string();
             }
             if let Some(proportions) = constraints.proportions.and_then(|c| c.max) {
-                model.universe_avatar_max_scales.proportion = proportions.to_string();
+                max_scales.proportion = proportions.to_string();
             }
+            model.universe_avatar_min_scales = Some(min_scales);
+            model.universe_avatar_max_scales = Some(max_scales);
         }
         if let Some(avatar_asset_overrides) = &config.avatar_asset_overrides {
-            for override_model in model.universe_avatar_asset_overrides.iter_mut() {
+            let mut overrides = ExperienceAvatarAssetOverride::defaults();
+            for override_model in overrides.iter_mut() {
                 if let Some(override_config) = match override_model.asset_type_id {
                     AssetTypeId::Face => avatar_asset_overrides.face,
                     AssetTypeId::Head => avatar_asset_overrides.head,
@@ -1070,6 +1595,7 @@ impl From<&ExperienceTargetConfigurationConfig> for ExperienceConfigurationModel
                     override_model.asset_id = Some(override_config);
                 }
             }
+            model.universe_avatar_asset_overrides = Some(overrides);
         }
         model
     }
@@ -1089,10 +1615,62 @@ pub struct PlaceTargetConfig {
     /// A file path to a Roblox place (either `.rbxl` or `.rbxlx`).
     pub file: Option<String>,
 
+    /// The ID of a place to copy when the place is created, instead of starting from a baseplate.
+    /// The template must be a place in an experience you can edit. Changing it has no effect on
+    /// places which have already been created, and it cannot be used for the start place.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     places:
+    ///       lobby:
+    ///         templatePlaceId: 1234567890
+    /// ```
+    pub template_place_id: Option<AssetId>,
+
+    /// Overrides the format of the place file, which is otherwise detected from its extension
+    /// (`.rbxl` files are binary and `.rbxlx` files are XML). The file must still have one of these
+    /// extensions.
+    ///
+    /// | Value      | Description                        |
+    /// |------------|------------------------------------|
+    /// | `'binary'` | The file is a binary place file.   |
+    /// | `'xml'`    | The file is an XML place file.     |
+    pub file_format: Option<PlaceFileFormatTargetConfig>,
+
+    /// A previously published version of the place to roll back to instead of uploading a
+    /// [`file`](#target-experience-places-label-file). Rolling back publishes the version's
+    /// contents again as a new version. Cannot be used together with `file`.
+    ///
+    /// ```yml title="Example"
+    /// target:
+    ///   experience:
+    ///     places:
+    ///       start:
+    ///         version: 42
+    /// ```
+    pub version: Option<u64>,
+
     /// A place's Roblox configuration.
     pub configuration: Option<PlaceTargetConfigurationConfig>,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaceFileFormatTargetConfig {
+    Binary,
+    Xml,
+}
+
+impl From<PlaceFileFormatTargetConfig> for PlaceFileFormat {
+    fn from(config: PlaceFileFormatTargetConfig) -> Self {
+        match config {
+            PlaceFileFormatTargetConfig::Binary => PlaceFileFormat::Binary,
+            PlaceFileFormatTargetConfig::Xml => PlaceFileFormat::Xml,
+        }
+    }
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct PlaceTargetConfigurationConfig {
@@ -1100,14 +1678,14 @@ pub struct PlaceTargetConfigurationConfig {
     ///
     /// The display name of the place on the Roblox website and in-game. If the
     /// place is an experience's start place, it will be the experience's
-    /// display name as well.
+    /// display name as well. Must be at most 50 characters.
     pub name: Option<String>,
 
     /// default('Created with Mantle')
     ///
     /// The descirption of the place on the Roblox website and in-game. If the
     /// place is an experience's start place, it will be the experience's
-    /// description as well.
+    /// description as well. Must be at most 1000 characters.
     pub description: Option<String>,
 
     /// default(50)
@@ -1152,6 +1730,27 @@ pub struct PlaceTargetConfigurationConfig {
     ///             reservedSlots: 5
     /// ```
     pub server_fill: Option<ServerFillTargetConfig>,
+
+    /// Whether or not content streaming is enabled for the place. If not specified, the place's
+    /// current setting will not be changed.
+    pub streaming_enabled: Option<bool>,
+
+    /// The minimum radius (in studs) around players that content will be streamed in. If not
+    /// specified, the place's current setting will not be changed.
+    pub streaming_min_radius: Option<u32>,
+
+    /// The radius (in studs) around players that content will be streamed in when possible. If
+    /// not specified, the place's current setting will not be changed.
+    pub streaming_target_radius: Option<u32>,
+
+    /// :::caution
+    /// Roblox does not support restricting individual places to specific devices, so setting this
+    /// property is an error. Use the experience's
+    /// [`playableDevices`](#target-experience-configuration-playabledevices) property instead.
+    /// :::
+    ///
+    /// The devices that the place can be played on.
+    pub playable_devices: Option<Vec<PlayableDeviceTargetConfig>>,
 }
 
 impl From<PlaceTargetConfigurationConfig> for PlaceConfigurationModel {
@@ -1180,6 +1779,9 @@ impl From<PlaceTargetConfigurationConfig> for PlaceConfigurationModel {
                 _ => None,
             }
         }
+        model.streaming_enabled = config.streaming_enabled;
+        model.streaming_min_radius = config.streaming_min_radius;
+        model.streaming_target_radius = config.streaming_target_radius;
         model
     }
 }
@@ -1232,3 +1834,149 @@ pub fn load_project_config(project: Option<&str>) -> Result<(PathBuf, Config), S
 
     Ok((project_path, config))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_field(model: &impl Serialize, field: &str) -> Option<serde_yaml::Value> {
+        match serde_yaml::to_value(model).unwrap() {
+            serde_yaml::Value::Mapping(mapping) => mapping
+                .get(&serde_yaml::Value::String(field.to_owned()))
+                .cloned(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn place_configuration_includes_streaming_settings() {
+        let config: PlaceTargetConfigurationConfig = serde_yaml::from_str(
+            "streamingEnabled: true\nstreamingMinRadius: 64\nstreamingTargetRadius: 256",
+        )
+        .unwrap();
+
+        let model = PlaceConfigurationModel::from(config);
+
+        assert_eq!(model.streaming_enabled, Some(true));
+        assert_eq!(model.streaming_min_radius, Some(64));
+        assert_eq!(model.streaming_target_radius, Some(256));
+        assert_eq!(
+            get_field(&model, "streamingEnabled"),
+            Some(serde_yaml::Value::Bool(true))
+        );
+        assert_eq!(
+            get_field(&model, "streamingMinRadius"),
+            Some(serde_yaml::to_value(64).unwrap())
+        );
+    }
+
+    #[test]
+    fn place_configuration_omits_unspecified_streaming_settings() {
+        let model = PlaceConfigurationModel::from(PlaceTargetConfigurationConfig::default());
+
+        assert_eq!(get_field(&model, "streamingEnabled"), None);
+        assert_eq!(get_field(&model, "streamingMinRadius"), None);
+        assert_eq!(get_field(&model, "streamingTargetRadius"), None);
+    }
+
+    #[test]
+    fn experience_configuration_includes_joint_positioning_and_mesh_settings() {
+        let config: ExperienceTargetConfigurationConfig = serde_yaml::from_str(
+            "avatarJointPositioningType: artificialPhysics\nallowMeshTextureApiAccess: true",
+        )
+        .unwrap();
+
+        let model = ExperienceConfigurationModel::from(&config);
+
+        assert_eq!(
+            get_field(&model, "universeJointPositioningType"),
+            Some(serde_yaml::Value::String("ArtificialPhysics".to_owned()))
+        );
+        assert_eq!(
+            get_field(&model, "isMeshTextureApiAccessAllowed"),
+            Some(serde_yaml::Value::Bool(true))
+        );
+    }
+
+    #[test]
+    fn experience_configuration_omits_unspecified_joint_positioning_and_mesh_settings() {
+        let model =
+            ExperienceConfigurationModel::from(&ExperienceTargetConfigurationConfig::default());
+
+        assert_eq!(get_field(&model, "universeJointPositioningType"), None);
+        assert_eq!(get_field(&model, "isMeshTextureApiAccessAllowed"), None);
+    }
+
+    #[test]
+    fn experience_configuration_includes_ad_settings() {
+        let config: ExperienceTargetConfigurationConfig =
+            serde_yaml::from_str("enableImmersiveAds: true\nenableRewardedVideoAds: false")
+                .unwrap();
+
+        let model = ExperienceConfigurationModel::from(&config);
+
+        assert_eq!(
+            get_field(&model, "isImmersiveAdsEnabled"),
+            Some(serde_yaml::Value::Bool(true))
+        );
+        assert_eq!(
+            get_field(&model, "isRewardedVideoAdsEnabled"),
+            Some(serde_yaml::Value::Bool(false))
+        );
+    }
+
+    #[test]
+    fn experience_configuration_omits_unspecified_ad_settings() {
+        let model =
+            ExperienceConfigurationModel::from(&ExperienceTargetConfigurationConfig::default());
+
+        assert_eq!(get_field(&model, "isImmersiveAdsEnabled"), None);
+        assert_eq!(get_field(&model, "isRewardedVideoAdsEnabled"), None);
+    }
+
+    #[test]
+    fn experience_configuration_only_includes_specified_fields() {
+        let config: ExperienceTargetConfigurationConfig =
+            serde_yaml::from_str("genre: rpg\nplayability: public\nallowThirdPartySales: true")
+                .unwrap();
+
+        let model = ExperienceConfigurationModel::from(&config);
+
+        // Any other fields would overwrite the experience's current settings
+        assert_eq!(
+            serde_yaml::to_value(&model).unwrap(),
+            serde_yaml::from_str::<serde_yaml::Value>(
+                "genre: RPG\nisFriendsOnly: false\npermissions:\n  IsThirdPartyPurchaseAllowed: true",
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn experience_configuration_omits_everything_by_default() {
+        let model =
+            ExperienceConfigurationModel::from(&ExperienceTargetConfigurationConfig::default());
+
+        assert_eq!(
+            serde_yaml::to_value(&model).unwrap(),
+            serde_yaml::Value::Mapping(Default::default())
+        );
+    }
+
+    #[test]
+    fn experience_configuration_fills_unspecified_avatar_scales_with_defaults() {
+        let config: ExperienceTargetConfigurationConfig =
+            serde_yaml::from_str("avatarScaleConstraints:\n  height:\n    min: 0.95").unwrap();
+
+        let model = ExperienceConfigurationModel::from(&config);
+
+        let min_scales = model.universe_avatar_min_scales.unwrap();
+        assert_eq!(min_scales.height, "0.95");
+        assert_eq!(
+            min_scales.width,
+            ExperienceAvatarScales::default_min().width
+        );
+        assert!(model.universe_avatar_max_scales.is_some());
+        assert!(model.universe_avatar_asset_overrides.is_none());
+    }
+}
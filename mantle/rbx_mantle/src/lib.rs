@@ -1,5 +1,6 @@
 pub mod config;
 pub mod project;
+pub mod remote_images;
 pub mod resource_graph;
 pub mod roblox_resource_manager;
 pub mod state;
@@ -82,6 +82,24 @@ fn override_yaml(a: &mut serde_yaml::Value, b: serde_yaml::Value) {
     }
 }
 
+/// The overrides are merged as untyped YAML, so errors from parsing the result point at the
+/// overrides which produced them.
+fn apply_target_overrides(
+    label: &str,
+    experience: ExperienceTargetConfig,
+    overrides: serde_yaml::Value,
+) -> Result<ExperienceTargetConfig, String> {
+    let mut as_value = serde_yaml::to_value(experience)
+        .map_err(|e| format!("Failed to serialize target: {}", e))?;
+    override_yaml(&mut as_value, overrides);
+    serde_yaml::from_value::<ExperienceTargetConfig>(as_value).map_err(|e| {
+        format!(
+            "Invalid config at environments[label={}].targetOverrides.experience\n\t{}",
+            label, e
+        )
+    })
+}
+
 fn get_target_config(
     environment: EnvironmentConfig,
     target: TargetConfig,
@@ -133,12 +151,11 @@ fn get_target_config(
 
             // Apply overrides last (they are the final trump)
             if let Some(overrides) = environment.target_overrides {
-                let overrides = serde_yaml::to_value(overrides).unwrap();
-                let mut as_value = serde_yaml::to_value(experience)
-                    .map_err(|e| format!("Failed to serialize target: {}", e))?;
-                override_yaml(&mut as_value, overrides);
-                experience = serde_yaml::from_value::<ExperienceTargetConfig>(as_value)
-                    .map_err(|e| format!("Failed to deserialize target: {}", e))?;
+                experience = apply_target_overrides(
+                    &environment.label,
+                    experience,
+                    serde_yaml::to_value(overrides).unwrap(),
+                )?;
             };
 
             TargetConfig::Experience(experience)
@@ -236,3 +253,43 @@ pub async fn load_project(
         owner_config: config.owner,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_experience(yaml: &str) -> ExperienceTargetConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn target_overrides_are_merged_into_the_target() {
+        let experience = get_experience("configuration:\n  playability: public\n  genre: rpg");
+        let overrides = serde_yaml::from_str("configuration:\n  playability: private").unwrap();
+
+        let experience = apply_target_overrides("staging", experience, overrides).unwrap();
+
+        let configuration = experience.configuration.unwrap();
+        assert!(matches!(
+            configuration.playability,
+            Some(PlayabilityTargetConfig::Private)
+        ));
+        assert!(configuration.genre.is_some());
+    }
+
+    #[test]
+    fn invalid_target_overrides_report_their_config_path() {
+        let experience = get_experience("configuration:\n  playability: public");
+        let overrides = serde_yaml::from_str("configuration:\n  playability: everyone").unwrap();
+
+        let error = match apply_target_overrides("staging", experience, overrides) {
+            Ok(_) => panic!("Expected the overrides to be invalid"),
+            Err(error) => error,
+        };
+
+        assert!(error.starts_with(
+            "Invalid config at environments[label=staging].targetOverrides.experience\n\t"
+        ));
+        assert!(error.contains("everyone"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use url::Url;
+
+use super::{
+    config::{ExperienceTargetConfig, TargetConfig, ThumbnailTargetConfig},
+    roblox_resource_manager::compute_hash,
+};
+
+/// The largest image which will be downloaded from a URL. Larger images are rejected before they
+/// are uploaded to Roblox.
+pub const MAX_REMOTE_IMAGE_SIZE: usize = 20 * 1024 * 1024;
+
+const REMOTE_IMAGE_TIMEOUT: Duration = Duration::from_secs(60);
+
+const REMOTE_IMAGE_CONTENT_TYPES: &[&str] = &["image/png", "image/jpeg", "image/jpg"];
+
+/// Gets the path which the image at the URL is downloaded to. Images are downloaded to the
+/// temporary directory each time the desired graph is built, so that their hashes can be computed
+/// and they can be uploaded in the same way as local files.
+pub fn get_remote_image_path(url: &str) -> PathBuf {
+    env::temp_dir()
+        .join("mantle-remote-images")
+        .join(compute_hash(url.as_bytes()))
+}
+
+fn get_remote_image_urls(target_config: &ExperienceTargetConfig) -> Vec<&Url> {
+    let mut urls: Vec<&Url> = Vec::new();
+    if let Some(icon_url) = &target_config.icon_url {
+        urls.push(icon_url);
+    }
+    for thumbnail in target_config.thumbnails.iter().flatten() {
+        if let ThumbnailTargetConfig::Options { url: Some(url), .. } = thumbnail {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+async fn download_remote_image(client: &reqwest::Client, url: &Url) -> Result<Vec<u8>, String> {
+    let mut response = client
+        .get(url.clone())
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Unable to download image {}: {}", url, e))?;
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_lowercase());
+    match content_type {
+        Some(content_type) if REMOTE_IMAGE_CONTENT_TYPES.contains(&content_type.as_str()) => {}
+        content_type => {
+            return Err(format!(
+                "Unable to download image {}: expected a PNG or JPEG but got {}",
+                url,
+                content_type.unwrap_or_else(|| "no content type".to_owned())
+            ))
+        }
+    }
+
+    let too_large_error = || {
+        format!(
+            "Unable to download image {}: the image is larger than {} bytes",
+            url, MAX_REMOTE_IMAGE_SIZE
+        )
+    };
+    if matches!(response.content_length(), Some(length) if length > MAX_REMOTE_IMAGE_SIZE as u64) {
+        return Err(too_large_error());
+    }
+
+    let mut data = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Unable to download image {}: {}", url, e))?
+    {
+        data.extend_from_slice(&chunk);
+        if data.len() > MAX_REMOTE_IMAGE_SIZE {
+            return Err(too_large_error());
+        }
+    }
+
+    Ok(data)
+}
+
+/// Downloads every image which the target config references by URL to its
+/// [`get_remote_image_path`].
+pub async fn download_remote_images(target_config: &TargetConfig) -> Result<(), String> {
+    let TargetConfig::Experience(target_config) = target_config;
+    let urls = get_remote_image_urls(target_config);
+    if urls.is_empty() {
+        return Ok(());
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(REMOTE_IMAGE_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Unable to create HTTP client: {}", e))?;
+
+    for url in urls {
+        let data = download_remote_image(&client, url).await?;
+
+        let path = get_remote_image_path(url.as_str());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Unable to create directory {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, data)
+            .map_err(|e| format!("Unable to save image {} to {}: {}", url, path.display(), e))?;
+    }
+
+    Ok(())
+}
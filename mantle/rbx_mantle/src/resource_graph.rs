@@ -1,10 +1,13 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
 use difference::Changeset;
+use futures::future::join_all;
 use serde::Serialize;
 use yansi::Paint;
 
@@ -50,10 +53,12 @@ pub trait Resource<TInputs, TOutputs>: Clone {
     fn get_outputs(&self) -> Option<TOutputs>;
     fn get_dependencies(&self) -> Vec<ResourceId>;
     fn set_outputs(&mut self, outputs: TOutputs);
+    fn get_metadata(&self) -> BTreeMap<String, String>;
+    fn set_metadata(&mut self, metadata: BTreeMap<String, String>);
 }
 
 #[async_trait]
-pub trait ResourceManager<TInputs, TOutputs> {
+pub trait ResourceManager<TInputs, TOutputs>: Sync {
     async fn get_create_price(
         &self,
         inputs: TInputs,
@@ -87,6 +92,136 @@ pub trait ResourceManager<TInputs, TOutputs> {
         outputs: TOutputs,
         dependency_outputs: Vec<TOutputs>,
     ) -> Result<(), String>;
+
+    /// Returns whether the resource still exists. Resources which cannot be looked up are assumed
+    /// to exist.
+    async fn exists(
+        &self,
+        outputs: TOutputs,
+        dependency_outputs: Vec<TOutputs>,
+    ) -> Result<bool, String>;
+
+    /// Compares the state which was last applied for a resource with its live state and returns
+    /// the names of any fields which were changed outside of Mantle.
+    async fn get_drifted_fields(
+        &self,
+        outputs: TOutputs,
+        dependency_outputs: Vec<TOutputs>,
+    ) -> Result<Vec<String>, String>;
+
+    /// Called before and after each create, update, and delete operation. Does nothing by default.
+    fn report(&self, _event: ResourceEvent) {}
+
+    /// Called when a create, update, or delete operation fails. Returns true if the manager
+    /// recovered from the error (e.g. by re-authenticating), in which case the operation is retried
+    /// once. Returns false by default.
+    async fn recover(&self, _error: &str) -> bool {
+        false
+    }
+
+    /// Whether the operations for a resource with these inputs may run at the same time as other
+    /// such operations. The manager is responsible for limiting how many run at once. Defaults to
+    /// false.
+    fn can_run_concurrently(&self, _inputs: &TInputs) -> bool {
+        false
+    }
+
+    /// Called after a resource is created or updated with the outputs of each of its
+    /// dependencies. Returns new outputs for the dependency if the operation changed it (e.g. a
+    /// place which became its experience's start place), or `None` if it was unaffected. Returns
+    /// `None` by default.
+    fn get_changed_dependency_outputs(
+        &self,
+        _inputs: &TInputs,
+        _outputs: &TOutputs,
+        _dependency_outputs: &TOutputs,
+    ) -> Option<TOutputs> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ResourceOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Clone, Debug)]
+pub enum ResourceEventKind {
+    Started,
+    Succeeded,
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct ResourceEvent {
+    /// The type of the resource, taken from its ID (e.g. `placeFile` for `placeFile_start`).
+    pub resource_type: String,
+    pub resource_id: ResourceId,
+    pub operation: ResourceOperation,
+    pub kind: ResourceEventKind,
+    /// The time since the operation started. Always zero for `Started` events.
+    pub elapsed: Duration,
+}
+
+impl ResourceEvent {
+    fn new(
+        resource_id: &str,
+        operation: ResourceOperation,
+        kind: ResourceEventKind,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            resource_type: resource_id
+                .split('_')
+                .next()
+                .unwrap_or(resource_id)
+                .to_owned(),
+            resource_id: resource_id.to_owned(),
+            operation,
+            kind,
+            elapsed,
+        }
+    }
+}
+
+fn report_started<TManager, TInputs, TOutputs>(
+    manager: &TManager,
+    resource_id: &str,
+    operation: ResourceOperation,
+) -> Instant
+where
+    TManager: ResourceManager<TInputs, TOutputs>,
+{
+    manager.report(ResourceEvent::new(
+        resource_id,
+        operation,
+        ResourceEventKind::Started,
+        Duration::ZERO,
+    ));
+    Instant::now()
+}
+
+fn report_finished<TManager, TInputs, TOutputs, T>(
+    manager: &TManager,
+    resource_id: &str,
+    operation: ResourceOperation,
+    started_at: Instant,
+    result: &Result<T, String>,
+) where
+    TManager: ResourceManager<TInputs, TOutputs>,
+{
+    let kind = match result {
+        Ok(_) => ResourceEventKind::Succeeded,
+        Err(error) => ResourceEventKind::Failed(error.clone()),
+    };
+    manager.report(ResourceEvent::new(
+        resource_id,
+        operation,
+        kind,
+        started_at.elapsed(),
+    ));
 }
 
 #[derive(Default, Clone)]
@@ -98,8 +233,41 @@ pub struct EvaluateResults {
     pub skipped_count: u32,
 }
 
+/// Controls how [`ResourceGraph::evaluate`] applies changes.
+pub struct EvaluateOptions<'a, TResource> {
+    /// Whether resources which cost Robux may be created or updated.
+    pub allow_purchases: bool,
+    /// Whether unchanged resources are compared with their live state so that changes made
+    /// outside of Mantle can be reported, and any which were deleted outside of Mantle are
+    /// created again.
+    pub refresh: bool,
+    /// The resources to limit the changes to, along with their dependencies. All resources are
+    /// changed when there are no targets.
+    pub targets: Option<&'a [ResourceId]>,
+    /// Stops new operations from being started once it is set. Cancellation is cooperative: an
+    /// operation which is already in progress (e.g. a place file upload) will be allowed to finish,
+    /// and resources which were not evaluated keep their previous state.
+    pub cancelled: Option<&'a AtomicBool>,
+    /// Called with the resources after each successful change so that an interrupted evaluation
+    /// can be resumed without repeating them.
+    pub checkpoint: Option<&'a dyn Fn(Vec<TResource>)>,
+}
+
+impl<'a, TResource> Default for EvaluateOptions<'a, TResource> {
+    fn default() -> Self {
+        Self {
+            allow_purchases: false,
+            refresh: false,
+            targets: None,
+            cancelled: None,
+            checkpoint: None,
+        }
+    }
+}
+
 enum OperationResult<TOutputs> {
     Skipped(String),
+    Untargeted,
     Noop,
     Failed(String),
     SucceededDelete,
@@ -120,6 +288,7 @@ where
     phantom_inputs: std::marker::PhantomData<TInputs>,
     phantom_outputs: std::marker::PhantomData<TOutputs>,
     resources: HashMap<ResourceId, TResource>,
+    metadata: BTreeMap<String, String>,
 }
 
 impl<TResource, TInputs, TOutputs> ResourceGraph<TResource, TInputs, TOutputs>
@@ -137,9 +306,17 @@ where
                 .collect(),
             phantom_inputs: PhantomData,
             phantom_outputs: PhantomData,
+            metadata: BTreeMap::new(),
         }
     }
 
+    /// Sets metadata which will be recorded on each resource that is created or updated when the
+    /// graph is evaluated. Metadata is kept in the state but is not used to decide whether a
+    /// resource has changed.
+    pub fn set_metadata(&mut self, metadata: BTreeMap<String, String>) {
+        self.metadata = metadata;
+    }
+
     pub fn get_outputs(&self, resource_id: &str) -> Option<TOutputs> {
         self.resources
             .get(resource_id)
@@ -187,6 +364,44 @@ where
         }
     }
 
+    /// Returns the resources which would be saved if the evaluation stopped now: resources which
+    /// have been evaluated are taken from this graph, and all other resources keep their previous
+    /// version.
+    fn get_checkpoint(
+        &self,
+        previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
+        evaluated: &HashSet<ResourceId>,
+    ) -> Vec<TResource> {
+        let resources = previous_graph
+            .resources
+            .iter()
+            .filter(|(id, _)| !evaluated.contains(*id))
+            .chain(
+                self.resources
+                    .iter()
+                    .filter(|(id, _)| evaluated.contains(*id)),
+            )
+            .map(|(_, resource)| resource.clone())
+            .collect::<Vec<_>>();
+        ResourceGraph::new(&resources).get_resource_list()
+    }
+
+    /// Returns the resources in the previous graph which are no longer in this graph (e.g.
+    /// because they were removed from the config) and should be deleted. They are returned in
+    /// reverse dependency order so that dependents are deleted before the resources they depend
+    /// on, and the experience is deleted last.
+    fn get_orphaned_resources(
+        &self,
+        previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
+    ) -> Result<Vec<ResourceId>, String> {
+        let mut previous_resource_order = previous_graph.get_topological_order()?;
+        previous_resource_order.reverse();
+        Ok(previous_resource_order
+            .into_iter()
+            .filter(|resource_id| !self.resources.contains_key(resource_id))
+            .collect())
+    }
+
     pub fn get_resource_list(&self) -> Vec<TResource> {
         self.get_topological_order()
             .unwrap()
@@ -212,6 +427,36 @@ where
         Some(dependency_outputs)
     }
 
+    /// Updates the outputs of a resource's dependencies which were changed by creating or updating
+    /// the resource. See [`ResourceManager::get_changed_dependency_outputs`].
+    fn update_dependency_outputs<TManager>(&mut self, manager: &TManager, resource_id: &str)
+    where
+        TManager: ResourceManager<TInputs, TOutputs>,
+    {
+        let resource = self.resources.get(resource_id).unwrap();
+        let (inputs, outputs) = match resource.get_outputs() {
+            Some(outputs) => (resource.get_inputs(), outputs),
+            None => return,
+        };
+        for dependency in resource.get_dependencies() {
+            if let Some(dependency_resource) = self.resources.get_mut(&dependency) {
+                let changed_outputs =
+                    dependency_resource
+                        .get_outputs()
+                        .and_then(|dependency_outputs| {
+                            manager.get_changed_dependency_outputs(
+                                &inputs,
+                                &outputs,
+                                &dependency_outputs,
+                            )
+                        });
+                if let Some(changed_outputs) = changed_outputs {
+                    dependency_resource.set_outputs(changed_outputs);
+                }
+            }
+        }
+    }
+
     fn get_dependency_outputs_hash(&self, dependency_outputs: Vec<TOutputs>) -> String {
         // TODO: Should we separate hashes from displays?
         let hash = serde_yaml::to_string(&dependency_outputs)
@@ -247,8 +492,10 @@ where
             }
             OperationResult::SucceededCreate(outputs) => {
                 // Update the resource with the new outputs
+                let metadata = self.metadata.clone();
                 let resource = self.resources.get_mut(resource_id).unwrap();
                 resource.set_outputs(outputs);
+                resource.set_metadata(metadata);
 
                 results.created_count += 1;
                 logger::end_action_with_results(
@@ -257,12 +504,16 @@ where
                 );
             }
             OperationResult::SucceededUpdate(outputs) => {
-                // Update the resource with the new outputs
+                // Update the resource with the new outputs, keeping any previous metadata which is
+                // not being replaced
+                let previous_resource = previous_graph.resources.get(resource_id).unwrap();
+                let mut metadata = previous_resource.get_metadata();
+                metadata.extend(self.metadata.clone());
                 let resource = self.resources.get_mut(resource_id).unwrap();
                 resource.set_outputs(outputs);
+                resource.set_metadata(metadata);
 
                 results.updated_count += 1;
-                let previous_resource = previous_graph.resources.get(resource_id).unwrap();
                 logger::end_action_with_results(
                     "Succeeded with outputs:",
                     get_changeset(
@@ -281,6 +532,7 @@ where
                         .get_outputs()
                         .expect("Existing resource should have outputs."),
                 );
+                resource.set_metadata(previous_resource.get_metadata());
 
                 results.noop_count += 1;
             }
@@ -298,6 +550,18 @@ where
                 results.skipped_count += 1;
                 logger::end_action(format!("Skipped: {}", Paint::yellow(reason)));
             }
+            OperationResult::Untargeted => {
+                // The resource was not selected by the targets. If the resource existed
+                // previously, we will copy the old version into this graph so that its outputs are
+                // still available to its dependents. Otherwise, we will remove this resource from
+                // the graph.
+                if let Some(previous_resource) = previous_graph.resources.get(resource_id) {
+                    self.resources
+                        .insert(resource_id.to_owned(), previous_resource.to_owned());
+                } else {
+                    self.resources.remove(resource_id);
+                }
+            }
             OperationResult::Failed(error) => {
                 // An error occurred while creating or updating the resource. If the
                 // resource existed previously, we will copy the old version into this
@@ -318,7 +582,7 @@ where
     async fn evaluate_delete<TManager>(
         &self,
         previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
-        manager: &mut TManager,
+        manager: &TManager,
         resource_id: &str,
     ) -> OperationResult<TOutputs>
     where
@@ -341,15 +605,43 @@ where
         logger::log("Inputs:");
         logger::log_changeset(get_changeset(&inputs_hash, ""));
 
-        match manager
-            .delete(
-                resource
-                    .get_outputs()
-                    .expect("Existing resource should have outputs."),
-                dependency_outputs,
-            )
-            .await
-        {
+        // Deletes are evaluated in reverse dependency order, so any resource which still depends
+        // on this one failed to be deleted (or was not targeted). Deleting this resource anyway
+        // would leave its dependents dangling, e.g. an experience being archived before its
+        // products have been removed.
+        let remaining_dependents = self
+            .resources
+            .iter()
+            .filter(|(_, other)| other.get_dependencies().iter().any(|id| id == resource_id))
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<_>>();
+        if !remaining_dependents.is_empty() {
+            return OperationResult::Skipped(format!(
+                "Resources which depend on it were not deleted: {}",
+                remaining_dependents.join(", ")
+            ));
+        }
+
+        let started_at = report_started(manager, resource_id, ResourceOperation::Delete);
+        let outputs = resource
+            .get_outputs()
+            .expect("Existing resource should have outputs.");
+        let mut result = manager
+            .delete(outputs.clone(), dependency_outputs.clone())
+            .await;
+        if let Err(error) = &result {
+            if manager.recover(error).await {
+                result = manager.delete(outputs, dependency_outputs).await;
+            }
+        }
+        report_finished(
+            manager,
+            resource_id,
+            ResourceOperation::Delete,
+            started_at,
+            &result,
+        );
+        match result {
             Ok(()) => OperationResult::SucceededDelete,
             Err(error) => OperationResult::Failed(error),
         }
@@ -358,9 +650,10 @@ where
     async fn evaluate_create_or_update<TManager>(
         &self,
         previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
-        manager: &mut TManager,
+        manager: &TManager,
         resource_id: &str,
         allow_purchases: bool,
+        refresh: bool,
     ) -> OperationResult<TOutputs>
     where
         TManager: ResourceManager<TInputs, TOutputs>,
@@ -397,7 +690,63 @@ where
             let dependencies_hash = self.get_dependency_outputs_hash(dependency_outputs.clone());
 
             if previous_hash == inputs_hash && previous_dependencies_hash == dependencies_hash {
-                // No changes
+                // No changes. When a refresh was requested, re-create the resource if it was
+                // deleted outside of Mantle and warn if it was changed outside of Mantle. These
+                // checks make requests for every unchanged resource, so they are not done by
+                // default.
+                if let (true, Some(outputs)) = (refresh, previous_resource.get_outputs()) {
+                    match manager
+                        .exists(outputs.clone(), dependency_outputs.clone())
+                        .await
+                    {
+                        Ok(false) => {
+                            logger::start_action(format!(
+                                "{} Re-creating: {}",
+                                Paint::green("+"),
+                                resource_id
+                            ));
+                            logger::log(Paint::yellow(format!(
+                                "{} was deleted outside of Mantle.",
+                                resource_id
+                            )));
+                            return self
+                                .create_resource(
+                                    manager,
+                                    resource_id,
+                                    dependency_outputs,
+                                    allow_purchases,
+                                )
+                                .await;
+                        }
+                        Ok(true) => {}
+                        Err(e) => {
+                            logger::log(Paint::yellow(format!(
+                                "Unable to check whether {} still exists: {}",
+                                resource_id, e
+                            )));
+                        }
+                    }
+
+                    match manager
+                        .get_drifted_fields(outputs, dependency_outputs)
+                        .await
+                    {
+                        Ok(drifted_fields) if !drifted_fields.is_empty() => {
+                            logger::log(Paint::yellow(format!(
+                                "{} was changed outside of Mantle. Drifted fields: {}",
+                                resource_id,
+                                drifted_fields.join(", ")
+                            )));
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            logger::log(Paint::yellow(format!(
+                                "Unable to check {} for changes made outside of Mantle: {}",
+                                resource_id, e
+                            )));
+                        }
+                    }
+                }
                 return OperationResult::Noop;
             }
 
@@ -442,10 +791,30 @@ where
                 Ok(_) => None,
             };
 
-            match manager
-                .update(resource.get_inputs(), outputs, dependency_outputs, price)
-                .await
-            {
+            let started_at = report_started(manager, resource_id, ResourceOperation::Update);
+            let mut result = manager
+                .update(
+                    resource.get_inputs(),
+                    outputs.clone(),
+                    dependency_outputs.clone(),
+                    price,
+                )
+                .await;
+            if let Err(error) = &result {
+                if manager.recover(error).await {
+                    result = manager
+                        .update(resource.get_inputs(), outputs, dependency_outputs, price)
+                        .await;
+                }
+            }
+            report_finished(
+                manager,
+                resource_id,
+                ResourceOperation::Update,
+                started_at,
+                &result,
+            );
+            match result {
                 Ok(outputs) => OperationResult::SucceededUpdate(outputs),
                 Err(error) => OperationResult::Failed(error),
             }
@@ -461,69 +830,194 @@ where
                     );
                 }
             };
-            let dependencies_hash = self.get_dependency_outputs_hash(dependency_outputs.clone());
+            self.create_resource(manager, resource_id, dependency_outputs, allow_purchases)
+                .await
+        }
+    }
 
-            logger::log("Dependencies:");
-            logger::log_changeset(get_changeset(&dependencies_hash, &dependencies_hash));
-            logger::log("Inputs:");
-            logger::log_changeset(get_changeset("", &inputs_hash));
+    async fn create_resource<TManager>(
+        &self,
+        manager: &TManager,
+        resource_id: &str,
+        dependency_outputs: Vec<TOutputs>,
+        allow_purchases: bool,
+    ) -> OperationResult<TOutputs>
+    where
+        TManager: ResourceManager<TInputs, TOutputs>,
+    {
+        let resource = self.resources.get(resource_id).unwrap();
+        let inputs_hash = resource.get_inputs_hash();
+        let dependencies_hash = self.get_dependency_outputs_hash(dependency_outputs.clone());
 
-            let price = match manager
-                .get_create_price(resource.get_inputs(), dependency_outputs.clone())
-                .await
-            {
-                Ok(Some(price)) if price > 0 => {
-                    if allow_purchases {
-                        logger::log("");
-                        logger::log(Paint::yellow(format!(
-                            "{} Robux will be charged from your account.",
+        logger::log("Dependencies:");
+        logger::log_changeset(get_changeset(&dependencies_hash, &dependencies_hash));
+        logger::log("Inputs:");
+        logger::log_changeset(get_changeset("", &inputs_hash));
+
+        let price = match manager
+            .get_create_price(resource.get_inputs(), dependency_outputs.clone())
+            .await
+        {
+            Ok(Some(price)) if price > 0 => {
+                if allow_purchases {
+                    logger::log("");
+                    logger::log(Paint::yellow(format!(
+                        "{} Robux will be charged from your account.",
+                        price
+                    )));
+                    Some(price)
+                } else {
+                    return OperationResult::Skipped(format!(
+                            "Resource would cost {} Robux to create. Give Mantle permission to make purchases with --allow-purchases.",
                             price
-                        )));
-                        Some(price)
-                    } else {
-                        return OperationResult::Skipped(format!(
-                                "Resource would cost {} Robux to create. Give Mantle permission to make purchases with --allow-purchases.",
-                                price
-                            ));
-                    }
+                        ));
                 }
-                Err(error) => return OperationResult::Failed(error),
-                Ok(_) => None,
-            };
+            }
+            Err(error) => return OperationResult::Failed(error),
+            Ok(_) => None,
+        };
 
-            match manager
-                .create(resource.get_inputs(), dependency_outputs, price)
-                .await
+        let started_at = report_started(manager, resource_id, ResourceOperation::Create);
+        let mut result = manager
+            .create(resource.get_inputs(), dependency_outputs.clone(), price)
+            .await;
+        if let Err(error) = &result {
+            if manager.recover(error).await {
+                result = manager
+                    .create(resource.get_inputs(), dependency_outputs, price)
+                    .await;
+            }
+        }
+        report_finished(
+            manager,
+            resource_id,
+            ResourceOperation::Create,
+            started_at,
+            &result,
+        );
+        match result {
+            Ok(outputs) => OperationResult::SucceededCreate(outputs),
+            Err(error) => OperationResult::Failed(error),
+        }
+    }
+
+    /// Returns the resources at the start of the provided order which can be evaluated at the same
+    /// time. Only resources which the manager allows to run concurrently and which do not depend
+    /// on each other are batched together; otherwise the batch contains a single resource.
+    fn get_concurrent_batch<'a, TManager>(
+        &self,
+        manager: &TManager,
+        resource_order: &'a [ResourceId],
+    ) -> Vec<&'a ResourceId>
+    where
+        TManager: ResourceManager<TInputs, TOutputs>,
+    {
+        let can_run_concurrently = |resource_id: &ResourceId| {
+            matches!(
+                self.resources.get(resource_id),
+                Some(resource) if manager.can_run_concurrently(&resource.get_inputs())
+            )
+        };
+
+        let mut batch: Vec<&ResourceId> = Vec::new();
+        for resource_id in resource_order {
+            if !batch.is_empty() {
+                let depends_on_batch = self.resources[resource_id]
+                    .get_dependencies()
+                    .iter()
+                    .any(|dependency| batch.contains(&dependency));
+                if !can_run_concurrently(resource_id) || depends_on_batch {
+                    break;
+                }
+            }
+            batch.push(resource_id);
+            if !can_run_concurrently(resource_id) {
+                break;
+            }
+        }
+        batch
+    }
+
+    /// Resolves the set of resources which should be evaluated for the provided target resource
+    /// IDs. Targets which do not exist yet will also include any of their dependencies which do
+    /// not exist yet so that they can be created.
+    pub fn get_targeted_resources(
+        &self,
+        previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
+        targets: &[ResourceId],
+    ) -> Result<HashSet<ResourceId>, String> {
+        let mut targeted = HashSet::new();
+        let mut pending: Vec<ResourceId> = Vec::new();
+        for target in targets {
+            if !self.resources.contains_key(target)
+                && !previous_graph.resources.contains_key(target)
             {
-                Ok(outputs) => OperationResult::SucceededCreate(outputs),
-                Err(error) => OperationResult::Failed(error),
+                return Err(format!("No resource found matching target {}", target));
             }
+            pending.push(target.clone());
         }
+
+        while let Some(resource_id) = pending.pop() {
+            if !targeted.insert(resource_id.clone()) {
+                continue;
+            }
+            if let Some(resource) = self.resources.get(&resource_id) {
+                for dependency in resource.get_dependencies() {
+                    if !previous_graph.resources.contains_key(&dependency) {
+                        pending.push(dependency);
+                    }
+                }
+            }
+        }
+
+        Ok(targeted)
     }
 
     pub async fn evaluate<TManager>(
         &mut self,
         previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
         manager: &mut TManager,
-        allow_purchases: bool,
+        options: EvaluateOptions<'_, TResource>,
     ) -> Result<EvaluateResults, String>
     where
         TManager: ResourceManager<TInputs, TOutputs>,
     {
+        let EvaluateOptions {
+            allow_purchases,
+            refresh,
+            targets,
+            cancelled,
+            checkpoint,
+        } = options;
         let mut results = EvaluateResults::default();
         let mut failures_count: u32 = 0;
 
-        // Iterate over previous resources in reverse order so that leaf resources are removed first
-        let mut previous_resource_order = previous_graph.get_topological_order()?;
-        previous_resource_order.reverse();
-        for resource_id in previous_resource_order.iter() {
-            if self.resources.contains_key(resource_id) {
-                continue;
-            }
+        // Resources which have been evaluated so far, used to report checkpoints of the completed
+        // changes so that an interrupted evaluation can be resumed without repeating them
+        let mut evaluated: HashSet<ResourceId> = HashSet::new();
 
-            let operation_result: OperationResult<TOutputs> = self
-                .evaluate_delete(previous_graph, manager, resource_id)
-                .await;
+        let targeted_resources = match targets {
+            Some(targets) => Some(self.get_targeted_resources(previous_graph, targets)?),
+            None => None,
+        };
+        let is_targeted = |resource_id: &ResourceId| match &targeted_resources {
+            Some(targeted_resources) => targeted_resources.contains(resource_id),
+            None => true,
+        };
+        let is_cancelled = || matches!(cancelled, Some(c) if c.load(Ordering::SeqCst));
+
+        for resource_id in self.get_orphaned_resources(previous_graph)?.iter() {
+            // Cancelled resources are treated like untargeted resources so that they keep their
+            // previous state
+            let operation_result: OperationResult<TOutputs> = if is_cancelled() {
+                OperationResult::Untargeted
+            } else if is_targeted(resource_id) {
+                self.evaluate_delete(previous_graph, manager, resource_id)
+                    .await
+            } else {
+                OperationResult::Untargeted
+            };
+            let changed = matches!(operation_result, OperationResult::SucceededDelete);
             self.handle_operation_result(
                 &mut results,
                 &mut failures_count,
@@ -531,20 +1025,78 @@ where
                 resource_id,
                 operation_result,
             );
+            evaluated.insert(resource_id.clone());
+            if let (true, Some(checkpoint)) = (changed, checkpoint) {
+                checkpoint(self.get_checkpoint(previous_graph, &evaluated));
+            }
         }
 
         let resource_order = self.get_topological_order()?;
-        for resource_id in resource_order.iter() {
-            let operation_result = self
-                .evaluate_create_or_update(previous_graph, manager, resource_id, allow_purchases)
-                .await;
-            self.handle_operation_result(
-                &mut results,
-                &mut failures_count,
-                previous_graph,
-                resource_id,
-                operation_result,
-            );
+        let mut index = 0;
+        while index < resource_order.len() {
+            let batch = self.get_concurrent_batch(manager, &resource_order[index..]);
+            index += batch.len();
+
+            // All operations in the batch are allowed to finish even if one of them fails. Any
+            // failures are reported when the results are handled below. When operations run at
+            // the same time, their logs are buffered and written in order once they have all
+            // finished so that they are not interleaved.
+            let graph = &*self;
+            let batch_manager = &*manager;
+            let (is_targeted, is_cancelled) = (&is_targeted, &is_cancelled);
+            let buffer_logs = batch.len() > 1;
+            let operation_results = join_all(batch.iter().map(|&resource_id| async move {
+                let operation = async move {
+                    if is_cancelled() {
+                        OperationResult::Untargeted
+                    } else if is_targeted(resource_id) {
+                        graph
+                            .evaluate_create_or_update(
+                                previous_graph,
+                                batch_manager,
+                                resource_id,
+                                allow_purchases,
+                                refresh,
+                            )
+                            .await
+                    } else {
+                        OperationResult::Untargeted
+                    }
+                };
+                if buffer_logs {
+                    let (operation_result, logs) = logger::buffered(operation).await;
+                    (operation_result, Some(logs))
+                } else {
+                    (operation.await, None)
+                }
+            }))
+            .await;
+
+            let mut changed = false;
+            for (resource_id, (operation_result, logs)) in batch.iter().zip(operation_results) {
+                if let Some(logs) = logs {
+                    logs.flush();
+                }
+                let succeeded = matches!(
+                    operation_result,
+                    OperationResult::SucceededCreate(_) | OperationResult::SucceededUpdate(_)
+                );
+                self.handle_operation_result(
+                    &mut results,
+                    &mut failures_count,
+                    previous_graph,
+                    resource_id,
+                    operation_result,
+                );
+                if succeeded {
+                    self.update_dependency_outputs(&*manager, resource_id);
+                }
+                changed |= succeeded;
+                evaluated.insert((*resource_id).clone());
+            }
+            if let (true, Some(checkpoint)) = (changed, checkpoint) {
+                checkpoint(self.get_checkpoint(previous_graph, &evaluated));
+            }
         }
 
         if failures_count > 0 {
@@ -552,6 +1104,8 @@ where
                 "Failed {} changes(s) while evaluating the resource graph. See above for more details.",
                 failures_count
             ))
+        } else if is_cancelled() {
+            Err("Cancelled before all changes were evaluated.".to_owned())
         } else {
             Ok(results)
         }
@@ -568,15 +1122,7 @@ where
             dependency_changes: BTreeMap::new(),
         };
 
-        // Iterate over previous resources in reverse order so that leaf resources are removed first
-        let mut previous_resource_order = previous_graph.get_topological_order()?;
-        previous_resource_order.reverse();
-
-        for resource_id in previous_resource_order.iter() {
-            if self.resources.contains_key(resource_id) {
-                continue;
-            }
-
+        for resource_id in self.get_orphaned_resources(previous_graph)?.iter() {
             diff.removals.insert(
                 resource_id.to_owned(),
                 ResourceRemoval {
@@ -645,6 +1191,269 @@ where
 
         Ok(diff)
     }
+
+    /// Returns the changes that evaluating this graph against `previous_graph` would make, in the
+    /// order they would be applied. Only the inputs are compared, so no requests are made to the
+    /// resource manager.
+    pub fn plan(
+        &self,
+        previous_graph: &ResourceGraph<TResource, TInputs, TOutputs>,
+    ) -> Result<Vec<PlannedChange>, String>
+    where
+        TInputs: Serialize,
+    {
+        let mut changes = Vec::new();
+
+        for resource_id in self.get_orphaned_resources(previous_graph)?.into_iter() {
+            let previous_inputs = previous_graph
+                .resources
+                .get(&resource_id)
+                .unwrap()
+                .get_inputs();
+            changes.push(PlannedChange::new(
+                resource_id,
+                PlannedAction::Delete,
+                get_input_field_changes(Some(&previous_inputs), None),
+                Vec::new(),
+            ));
+        }
+
+        let mut changed_resource_ids: HashSet<ResourceId> = HashSet::new();
+        for resource_id in self.get_topological_order()?.into_iter() {
+            let resource = self.resources.get(&resource_id).unwrap();
+            let inputs = resource.get_inputs();
+
+            let (action, field_changes, changed_dependencies) =
+                match previous_graph.resources.get(&resource_id) {
+                    Some(previous_resource) => {
+                        let field_changes = get_input_field_changes(
+                            Some(&previous_resource.get_inputs()),
+                            Some(&inputs),
+                        );
+                        let changed_dependencies: Vec<_> = resource
+                            .get_dependencies()
+                            .into_iter()
+                            .filter(|x| changed_resource_ids.contains(x))
+                            .collect();
+                        if field_changes.is_empty() && changed_dependencies.is_empty() {
+                            continue;
+                        }
+                        (PlannedAction::Update, field_changes, changed_dependencies)
+                    }
+                    None => (
+                        PlannedAction::Create,
+                        get_input_field_changes(None, Some(&inputs)),
+                        Vec::new(),
+                    ),
+                };
+
+            changed_resource_ids.insert(resource_id.clone());
+            changes.push(PlannedChange::new(
+                resource_id,
+                action,
+                field_changes,
+                changed_dependencies,
+            ));
+        }
+
+        Ok(changes)
+    }
+}
+
+/// Formats an input value on a single line for reports.
+fn format_input_value(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::Null => "null".to_owned(),
+        serde_yaml::Value::Bool(value) => value.to_string(),
+        serde_yaml::Value::Number(value) => value.to_string(),
+        serde_yaml::Value::String(value) => value.clone(),
+        serde_yaml::Value::Sequence(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(format_input_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        serde_yaml::Value::Mapping(fields) => format!(
+            "{{{}}}",
+            fields
+                .iter()
+                .map(|(key, value)| format!(
+                    "{}: {}",
+                    format_input_value(key),
+                    format_input_value(value)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Compares two values, ignoring the order of the items in any lists.
+fn is_same_value(previous: &serde_yaml::Value, current: &serde_yaml::Value) -> bool {
+    match (previous, current) {
+        (serde_yaml::Value::Sequence(previous), serde_yaml::Value::Sequence(current)) => {
+            let mut unmatched: Vec<&serde_yaml::Value> = current.iter().collect();
+            previous.len() == current.len()
+                && previous.iter().all(|item| {
+                    match unmatched
+                        .iter()
+                        .position(|other| is_same_value(item, other))
+                    {
+                        Some(index) => {
+                            unmatched.swap_remove(index);
+                            true
+                        }
+                        None => false,
+                    }
+                })
+        }
+        (serde_yaml::Value::Mapping(previous), serde_yaml::Value::Mapping(current)) => {
+            previous.len() == current.len()
+                && previous.iter().all(|(key, value)| {
+                    matches!(current.get(key), Some(other) if is_same_value(value, other))
+                })
+        }
+        (previous, current) => previous == current,
+    }
+}
+
+/// Returns the top-level fields which differ between two serialized models, along with their
+/// values. Fields which are only present in one of the models (e.g. a field which was removed) are
+/// included, while lists which only differ in the order of their items are not.
+fn get_value_field_changes(
+    previous: serde_yaml::Value,
+    current: serde_yaml::Value,
+) -> Vec<FieldChange> {
+    match (previous, current) {
+        (serde_yaml::Value::Mapping(previous), serde_yaml::Value::Mapping(current)) => {
+            let mut changes: Vec<FieldChange> = previous
+                .iter()
+                .chain(current.iter())
+                .filter(|(key, _)| match (previous.get(key), current.get(key)) {
+                    (Some(previous), Some(current)) => !is_same_value(previous, current),
+                    _ => true,
+                })
+                .map(|(key, _)| FieldChange {
+                    field: key.as_str().unwrap_or_default().to_owned(),
+                    previous_value: previous.get(key).map(format_input_value),
+                    current_value: current.get(key).map(format_input_value),
+                })
+                .collect();
+            changes.sort_by(|a, b| a.field.cmp(&b.field));
+            changes.dedup_by(|a, b| a.field == b.field);
+            changes
+        }
+        (previous, current) if is_same_value(&previous, &current) => Vec::new(),
+        _ => vec![FieldChange {
+            field: "*".to_owned(),
+            previous_value: None,
+            current_value: None,
+        }],
+    }
+}
+
+/// Returns the top-level fields which differ between two models. See [`get_value_field_changes`].
+pub(crate) fn get_changed_fields<T>(previous: &T, current: &T) -> Vec<String>
+where
+    T: Serialize,
+{
+    get_value_field_changes(
+        serde_yaml::to_value(previous).unwrap_or(serde_yaml::Value::Null),
+        serde_yaml::to_value(current).unwrap_or(serde_yaml::Value::Null),
+    )
+    .into_iter()
+    .map(|change| change.field)
+    .collect()
+}
+
+/// Returns the fields of a resource's inputs which differ between two versions of them, with the
+/// same comparison as [`get_changed_fields`]. Inputs are serialized in the form
+/// `variant: { field: value }`, so the fields of the variant are compared. Missing inputs have no
+/// fields.
+fn get_input_field_changes<TInputs>(
+    previous: Option<&TInputs>,
+    current: Option<&TInputs>,
+) -> Vec<FieldChange>
+where
+    TInputs: Serialize,
+{
+    fn get_fields<TInputs: Serialize>(inputs: Option<&TInputs>) -> serde_yaml::Value {
+        let value = match inputs {
+            Some(inputs) => serde_yaml::to_value(inputs).unwrap_or(serde_yaml::Value::Null),
+            None => return serde_yaml::Value::Mapping(serde_yaml::Mapping::new()),
+        };
+        match value {
+            serde_yaml::Value::Mapping(variant) if variant.len() == 1 => {
+                match variant.into_iter().next() {
+                    Some((_, fields @ serde_yaml::Value::Mapping(_))) => fields,
+                    Some((name, fields)) => {
+                        serde_yaml::Value::Mapping([(name, fields)].into_iter().collect())
+                    }
+                    None => serde_yaml::Value::Null,
+                }
+            }
+            value => value,
+        }
+    }
+
+    get_value_field_changes(get_fields(previous), get_fields(current))
+}
+
+/// A change to one of a resource's input fields. Values are missing when the field is not set.
+#[derive(Clone, Debug)]
+pub struct FieldChange {
+    pub field: String,
+    pub previous_value: Option<String>,
+    pub current_value: Option<String>,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum PlannedAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedChange {
+    pub resource_type: String,
+    pub resource_id: ResourceId,
+    pub action: PlannedAction,
+    /// The input fields which will be set (creates), changed (updates), or cleared (deletes).
+    /// Changes to files are reported through their `fileHash` field.
+    pub changed_fields: Vec<String>,
+    /// The previous and current values of the changed fields, used for human-readable reports.
+    #[serde(skip)]
+    pub field_changes: Vec<FieldChange>,
+    /// Changed dependencies whose new outputs may cause this resource to be updated even though
+    /// its own inputs are unchanged.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changed_dependencies: Vec<ResourceId>,
+}
+
+impl PlannedChange {
+    fn new(
+        resource_id: ResourceId,
+        action: PlannedAction,
+        field_changes: Vec<FieldChange>,
+        changed_dependencies: Vec<ResourceId>,
+    ) -> Self {
+        Self {
+            resource_type: resource_id.split('_').next().unwrap_or_default().to_owned(),
+            resource_id,
+            action,
+            changed_fields: field_changes
+                .iter()
+                .map(|change| change.field.clone())
+                .collect(),
+            field_changes,
+            changed_dependencies,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -680,3 +1489,522 @@ pub struct ResourceDependencyChange {
     pub current_inputs_hash: String,
     pub changed_dependencies: Vec<ResourceId>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// A resource whose inputs and outputs are both plain strings.
+    #[derive(Clone)]
+    struct TestResource {
+        id: ResourceId,
+        inputs: String,
+        outputs: Option<String>,
+        dependencies: Vec<ResourceId>,
+        metadata: BTreeMap<String, String>,
+    }
+
+    impl TestResource {
+        fn new(id: &str, inputs: &str, dependencies: &[&TestResource]) -> Self {
+            Self {
+                id: id.to_owned(),
+                inputs: inputs.to_owned(),
+                outputs: None,
+                dependencies: dependencies.iter().map(|d| d.get_id()).collect(),
+                metadata: BTreeMap::new(),
+            }
+        }
+
+        fn existing(id: &str, inputs: &str, dependencies: &[&TestResource]) -> Self {
+            Self {
+                outputs: Some(inputs.to_owned()),
+                ..Self::new(id, inputs, dependencies)
+            }
+        }
+    }
+
+    impl Resource<String, String> for TestResource {
+        fn get_id(&self) -> ResourceId {
+            self.id.clone()
+        }
+
+        fn get_inputs_hash(&self) -> String {
+            self.inputs.clone()
+        }
+
+        fn get_outputs_hash(&self) -> String {
+            self.outputs.clone().unwrap_or_default()
+        }
+
+        fn get_inputs(&self) -> String {
+            self.inputs.clone()
+        }
+
+        fn get_outputs(&self) -> Option<String> {
+            self.outputs.clone()
+        }
+
+        fn get_dependencies(&self) -> Vec<ResourceId> {
+            self.dependencies.clone()
+        }
+
+        fn set_outputs(&mut self, outputs: String) {
+            self.outputs = Some(outputs);
+        }
+
+        fn get_metadata(&self) -> BTreeMap<String, String> {
+            self.metadata.clone()
+        }
+
+        fn set_metadata(&mut self, metadata: BTreeMap<String, String>) {
+            self.metadata = metadata;
+        }
+    }
+
+    type TestGraph = ResourceGraph<TestResource, String, String>;
+
+    /// Records each operation and fails any operation on the inputs or outputs in `failing`.
+    #[derive(Default)]
+    struct TestManager {
+        failing: Vec<String>,
+        operations: Mutex<Vec<String>>,
+    }
+
+    impl TestManager {
+        fn failing(failing: &[&str]) -> Self {
+            Self {
+                failing: failing.iter().map(|value| value.to_string()).collect(),
+                ..Default::default()
+            }
+        }
+
+        fn record(&self, operation: &str, value: &str) -> Result<(), String> {
+            self.operations
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", operation, value));
+            if self.failing.iter().any(|failing| failing == value) {
+                return Err(format!("Failed to {} {}", operation, value));
+            }
+            Ok(())
+        }
+
+        fn operations(&self) -> Vec<String> {
+            self.operations.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ResourceManager<String, String> for TestManager {
+        async fn get_create_price(
+            &self,
+            _inputs: String,
+            _dependency_outputs: Vec<String>,
+        ) -> Result<Option<u32>, String> {
+            Ok(None)
+        }
+
+        async fn create(
+            &self,
+            inputs: String,
+            _dependency_outputs: Vec<String>,
+            _price: Option<u32>,
+        ) -> Result<String, String> {
+            self.record("create", &inputs)?;
+            Ok(inputs)
+        }
+
+        async fn get_update_price(
+            &self,
+            _inputs: String,
+            _outputs: String,
+            _dependency_outputs: Vec<String>,
+        ) -> Result<Option<u32>, String> {
+            Ok(None)
+        }
+
+        async fn update(
+            &self,
+            inputs: String,
+            _outputs: String,
+            _dependency_outputs: Vec<String>,
+            _price: Option<u32>,
+        ) -> Result<String, String> {
+            self.record("update", &inputs)?;
+            Ok(inputs)
+        }
+
+        async fn delete(
+            &self,
+            outputs: String,
+            _dependency_outputs: Vec<String>,
+        ) -> Result<(), String> {
+            self.record("delete", &outputs)
+        }
+
+        async fn exists(
+            &self,
+            _outputs: String,
+            _dependency_outputs: Vec<String>,
+        ) -> Result<bool, String> {
+            Ok(true)
+        }
+
+        async fn get_drifted_fields(
+            &self,
+            _outputs: String,
+            _dependency_outputs: Vec<String>,
+        ) -> Result<Vec<String>, String> {
+            Ok(Vec::new())
+        }
+
+        /// Resources whose inputs start with "start" mark their dependencies with their outputs.
+        fn get_changed_dependency_outputs(
+            &self,
+            inputs: &String,
+            outputs: &String,
+            dependency_outputs: &String,
+        ) -> Option<String> {
+            if inputs.starts_with("start") {
+                Some(format!("{} ({})", dependency_outputs, outputs))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn get_resource_ids(resources: &[TestResource]) -> Vec<ResourceId> {
+        resources.iter().map(|resource| resource.get_id()).collect()
+    }
+
+    #[tokio::test]
+    async fn delete_is_skipped_while_dependents_remain() {
+        let experience = TestResource::existing("experience_singleton", "experience", &[]);
+        let product = TestResource::existing("product_gold", "product", &[&experience]);
+        let previous_graph = TestGraph::new(&[experience, product]);
+        let mut next_graph = TestGraph::new(&[]);
+        let mut manager = TestManager::failing(&["product"]);
+
+        let result = next_graph
+            .evaluate(&previous_graph, &mut manager, EvaluateOptions::default())
+            .await;
+
+        assert!(result.is_err());
+        // The experience is not deleted because the product which depends on it failed to be
+        // deleted, so both are kept in the graph
+        assert_eq!(manager.operations(), vec!["delete product"]);
+        assert_eq!(
+            get_resource_ids(&next_graph.get_resource_list()),
+            vec!["experience_singleton", "product_gold"]
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_continues_once_dependents_are_deleted() {
+        let experience = TestResource::existing("experience_singleton", "experience", &[]);
+        let product = TestResource::existing("product_gold", "product", &[&experience]);
+        let previous_graph = TestGraph::new(&[experience, product]);
+        let mut next_graph = TestGraph::new(&[]);
+        let mut manager = TestManager::default();
+
+        let results = next_graph
+            .evaluate(&previous_graph, &mut manager, EvaluateOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(results.deleted_count, 2);
+        assert_eq!(
+            manager.operations(),
+            vec!["delete product", "delete experience"]
+        );
+        assert!(next_graph.get_resource_list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn checkpoint_is_reported_after_each_change() {
+        let a = TestResource::new("a_singleton", "a", &[]);
+        let b = TestResource::new("b_singleton", "b", &[&a]);
+        let c = TestResource::new("c_singleton", "c", &[&b]);
+        let previous_graph = TestGraph::new(&[]);
+        let mut next_graph = TestGraph::new(&[a, b, c]);
+        let mut manager = TestManager::default();
+        let checkpoints = Mutex::new(Vec::new());
+        let checkpoint = |resources: Vec<TestResource>| {
+            checkpoints
+                .lock()
+                .unwrap()
+                .push(get_resource_ids(&resources))
+        };
+
+        next_graph
+            .evaluate(
+                &previous_graph,
+                &mut manager,
+                EvaluateOptions {
+                    checkpoint: Some(&checkpoint),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            checkpoints.into_inner().unwrap(),
+            vec![
+                vec!["a_singleton"],
+                vec!["a_singleton", "b_singleton"],
+                vec!["a_singleton", "b_singleton", "c_singleton"],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn checkpoint_keeps_previous_version_of_unevaluated_resources() {
+        let previous_a = TestResource::existing("a_singleton", "a v1", &[]);
+        let previous_b = TestResource::existing("b_singleton", "b v1", &[&previous_a]);
+        let previous_graph = TestGraph::new(&[previous_a, previous_b]);
+        let a = TestResource::new("a_singleton", "a v2", &[]);
+        let b = TestResource::new("b_singleton", "b v2", &[&a]);
+        let mut next_graph = TestGraph::new(&[a, b]);
+        let mut manager = TestManager::failing(&["b v2"]);
+        let checkpoints = Mutex::new(Vec::new());
+        let checkpoint = |resources: Vec<TestResource>| {
+            checkpoints.lock().unwrap().push(
+                resources
+                    .iter()
+                    .map(|resource| resource.get_outputs())
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let result = next_graph
+            .evaluate(
+                &previous_graph,
+                &mut manager,
+                EvaluateOptions {
+                    checkpoint: Some(&checkpoint),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        // Only the successful update of a is reported, and b keeps its previous outputs
+        assert!(result.is_err());
+        assert_eq!(
+            checkpoints.into_inner().unwrap(),
+            vec![vec![Some("a v2".to_owned()), Some("b v1".to_owned())]]
+        );
+    }
+
+    #[test]
+    fn orphaned_resources_are_in_reverse_dependency_order() {
+        let experience = TestResource::existing("experience_singleton", "experience", &[]);
+        let place = TestResource::existing("place_start", "place", &[&experience]);
+        let configuration =
+            TestResource::existing("placeConfiguration_start", "configuration", &[&place]);
+        let previous_graph = TestGraph::new(&[experience.clone(), place, configuration]);
+        let next_graph = TestGraph::new(&[experience]);
+
+        assert_eq!(
+            next_graph.get_orphaned_resources(&previous_graph).unwrap(),
+            vec!["placeConfiguration_start", "place_start"]
+        );
+    }
+
+    #[test]
+    fn changed_resources_are_not_orphaned() {
+        let previous_graph =
+            TestGraph::new(&[TestResource::existing("experience_singleton", "v1", &[])]);
+        let next_graph = TestGraph::new(&[TestResource::new("experience_singleton", "v2", &[])]);
+
+        assert!(next_graph
+            .get_orphaned_resources(&previous_graph)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_run_is_resumed_from_the_last_checkpoint() {
+        let get_desired_graph = || {
+            let a = TestResource::new("a_singleton", "a", &[]);
+            let b = TestResource::new("b_singleton", "b", &[&a]);
+            let c = TestResource::new("c_singleton", "c", &[&b]);
+            TestGraph::new(&[a, b, c])
+        };
+        let checkpoints = Mutex::new(Vec::new());
+        let checkpoint = |resources: Vec<TestResource>| checkpoints.lock().unwrap().push(resources);
+
+        let mut failed_graph = get_desired_graph();
+        let mut failing_manager = TestManager::failing(&["c"]);
+        let result = failed_graph
+            .evaluate(
+                &TestGraph::new(&[]),
+                &mut failing_manager,
+                EvaluateOptions {
+                    checkpoint: Some(&checkpoint),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            failing_manager.operations(),
+            vec!["create a", "create b", "create c"]
+        );
+
+        // The next run starts from the state which was saved by the last checkpoint
+        let saved_resources = checkpoints.lock().unwrap().pop().unwrap();
+        let mut resumed_graph = get_desired_graph();
+        let mut manager = TestManager::default();
+        let results = resumed_graph
+            .evaluate(
+                &TestGraph::new(&saved_resources),
+                &mut manager,
+                EvaluateOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(manager.operations(), vec!["create c"]);
+        assert_eq!(results.created_count, 1);
+        assert_eq!(results.noop_count, 2);
+    }
+
+    #[test]
+    fn plan_only_includes_changed_resources_and_their_dependents() {
+        let experience = TestResource::existing("experience_singleton", "experience", &[]);
+        let place = TestResource::existing("place_start", "place", &[&experience]);
+        let badge = TestResource::existing("badge_first", "badge", &[&experience]);
+        let previous_graph = TestGraph::new(&[experience.clone(), place.clone(), badge.clone()]);
+
+        let next_experience = TestResource::new("experience_singleton", "experience v2", &[]);
+        let next_place = TestResource::new("place_start", "place", &[&next_experience]);
+        let next_graph = TestGraph::new(&[next_experience, next_place]);
+
+        let changes = next_graph.plan(&previous_graph).unwrap();
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|change| {
+                (
+                    change.resource_id.as_str(),
+                    change.action,
+                    change.changed_dependencies.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("badge_first", PlannedAction::Delete, vec![]),
+                ("experience_singleton", PlannedAction::Update, vec![]),
+                (
+                    "place_start",
+                    PlannedAction::Update,
+                    vec!["experience_singleton".to_owned()]
+                ),
+            ]
+        );
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    enum TestInputs {
+        Place {
+            name: String,
+            tags: Vec<String>,
+            description: Option<String>,
+        },
+    }
+
+    fn get_place_inputs(name: &str, tags: &[&str], description: Option<&str>) -> TestInputs {
+        TestInputs::Place {
+            name: name.to_owned(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            description: description.map(|description| description.to_owned()),
+        }
+    }
+
+    #[test]
+    fn input_changes_include_changed_fields_with_their_values() {
+        let previous = get_place_inputs("Lobby", &["a"], None);
+        let current = get_place_inputs("Main", &["a"], Some("The main place"));
+
+        let changes = get_input_field_changes(Some(&previous), Some(&current));
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|change| {
+                (
+                    change.field.as_str(),
+                    change.previous_value.as_deref(),
+                    change.current_value.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("description", Some("null"), Some("The main place")),
+                ("name", Some("Lobby"), Some("Main")),
+            ]
+        );
+    }
+
+    #[test]
+    fn input_changes_ignore_list_ordering() {
+        let previous = get_place_inputs("Lobby", &["a", "b", "b"], None);
+        let reordered = get_place_inputs("Lobby", &["b", "a", "b"], None);
+        let changed = get_place_inputs("Lobby", &["a", "a", "b"], None);
+
+        assert!(get_input_field_changes(Some(&previous), Some(&reordered)).is_empty());
+        assert_eq!(
+            get_input_field_changes(Some(&previous), Some(&changed))
+                .into_iter()
+                .map(|change| change.field)
+                .collect::<Vec<_>>(),
+            vec!["tags"]
+        );
+    }
+
+    #[test]
+    fn input_changes_of_created_resources_include_every_field() {
+        let current = get_place_inputs("Lobby", &[], None);
+
+        let changes = get_input_field_changes(None, Some(&current));
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.field.as_str(), change.previous_value.is_none()))
+                .collect::<Vec<_>>(),
+            vec![("description", true), ("name", true), ("tags", true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn dependency_outputs_changed_by_an_operation_are_kept() {
+        let experience = TestResource::existing("experience_singleton", "experience", &[]);
+        let previous_graph = TestGraph::new(&[TestResource::existing(
+            "experience_singleton",
+            "experience",
+            &[],
+        )]);
+        let place = TestResource::new("place_start", "start place", &[&experience]);
+        let mut next_graph = TestGraph::new(&[experience, place]);
+        let mut manager = TestManager::default();
+
+        next_graph
+            .evaluate(&previous_graph, &mut manager, EvaluateOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            next_graph.get_outputs("experience_singleton").as_deref(),
+            Some("experience (start place)")
+        );
+        assert_eq!(
+            next_graph.get_outputs("place_start").as_deref(),
+            Some("start place")
+        );
+    }
+}
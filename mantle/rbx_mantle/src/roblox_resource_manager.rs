@@ -1,6 +1,12 @@
 use std::{
-    env,
+    collections::{BTreeMap, HashMap, VecDeque},
+    env, fs,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{self, Instant},
 };
 
 use async_trait::async_trait;
@@ -14,16 +20,30 @@ use rbx_api::{
     assets::models::{
         CreateAssetQuota, CreateAudioAssetResponse, CreateImageAssetResponse, QuotaDuration,
     },
-    badges::models::CreateBadgeResponse,
+    badges::models::{CreateBadgeResponse, GetBadgeResponse},
     developer_products::models::{
         CreateDeveloperProductIconResponse, CreateDeveloperProductResponse,
         GetDeveloperProductResponse,
     },
-    experiences::models::{CreateExperienceResponse, ExperienceConfigurationModel},
+    developer_subscriptions::models::{
+        CreateDeveloperSubscriptionResponse, DeveloperSubscriptionPeriod,
+        GetDeveloperSubscriptionResponse,
+    },
+    errors::{RobloxApiError, RobloxApiResult},
+    experiences::models::{
+        CreateExperienceResponse, ExperienceAvatarAssetOverride, ExperienceAvatarType,
+        ExperienceCollaboratorPermission, ExperienceCollaboratorSubjectType,
+        ExperienceConfigurationModel, ExperienceLocalizedMetadata, GetExperienceResponse,
+        PrivateServerSettingsModel, UniverseAvatarModel,
+    },
     game_passes::models::{CreateGamePassResponse, GetGamePassResponse},
     models::{AssetId, AssetTypeId, CreatorType, UploadImageResponse},
     notifications::models::CreateNotificationResponse,
-    places::models::{GetPlaceResponse, PlaceConfigurationModel},
+    places::{
+        models::{GetPlaceResponse, PlaceConfigurationModel, PlaceFileFormat},
+        UploadProgressCallback,
+    },
+    retry::DEFAULT_RETRY_BUDGET,
     social_links::models::{CreateSocialLinkResponse, SocialLinkType},
     spatial_voice::models::UpdateSpatialVoiceSettingsRequest,
     RobloxApi,
@@ -33,17 +53,28 @@ use rbxcloud::rbx::{
     types::{PlaceId, UniverseId},
     v1::{PublishVersionType, RbxCloud},
 };
-use serde::{Deserialize, Serialize};
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml::{Mapping, Value};
+use sha2::{Digest, Sha256};
+use tokio::sync::{Mutex as AsyncMutex, Semaphore};
 use yansi::Paint;
 
-use super::resource_graph::{
-    all_outputs, optional_output, single_output, Resource, ResourceId, ResourceManager,
+use super::{
+    remote_images::get_remote_image_path,
+    resource_graph::{
+        all_outputs, get_changed_fields, optional_output, single_output, Resource, ResourceEvent,
+        ResourceId, ResourceManager,
+    },
 };
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ExperienceInputs {
     pub group_id: Option<AssetId>,
+    /// The existing experience to deploy to. A new experience is created if it is not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<AssetId>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -59,10 +90,87 @@ pub struct FileInputs {
     pub file_hash: String,
 }
 
+/// Exactly one of `file_path` (with `file_hash`) or `asset_id` is set. Icons with an `asset_id`
+/// reuse an existing image asset, such as one shared by several products, instead of uploading a
+/// file.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductIconInputs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<AssetId>,
+}
+
+/// Exactly one of `file_path` or `url` is set. Icons with a `url` upload the image downloaded from
+/// it (see [`get_remote_image_path`]), and their `file_hash` is the hash of the downloaded image.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceIconInputs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub file_hash: String,
+}
+
+/// Exactly one of `file_path` or `url` (with `file_hash`) or `asset_id` is set. Thumbnails with an
+/// `asset_id` register an existing asset, such as a video, instead of uploading a file. Thumbnails
+/// with a `url` upload the image downloaded from it (see [`get_remote_image_path`]), and their
+/// `file_hash` is the hash of the downloaded image.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceThumbnailInputs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<AssetId>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Exactly one of `file_path` (with `file_hash`) or `version` is set. Place files with a `version`
+/// roll the place back to that version instead of uploading a file.
+pub struct PlaceFileInputs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    /// Overrides the format detected from the file's extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_format: Option<PlaceFileFormat>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceFileBytesInputs {
+    /// The place file data. It is not persisted to the state file; `file_hash` is used to detect
+    /// changes instead.
+    #[serde(skip)]
+    pub bytes: Vec<u8>,
+    pub is_xml: bool,
+    pub file_hash: String,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaceInputs {
     pub is_start: bool,
+    /// The name to give the place when it is created, so that it is not left with the default
+    /// name if configuring the place fails. Renames are handled by the place's configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The place to copy when the place is created. Changing it does not affect existing places.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_place_id: Option<AssetId>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -79,6 +187,9 @@ pub struct ProductInputs {
     pub name: String,
     pub description: String,
     pub price: u32,
+    /// Prices in Robux keyed by region code, which override `price` in those regions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regional_prices: Option<BTreeMap<String, u32>>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -91,6 +202,17 @@ pub struct PassInputs {
     pub icon_file_hash: String,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionInputs {
+    pub name: String,
+    pub description: String,
+    pub price: u32,
+    pub period: DeveloperSubscriptionPeriod,
+    pub icon_file_path: String,
+    pub icon_file_hash: String,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BadgeInputs {
@@ -114,6 +236,20 @@ pub struct AssetAliasInputs {
     pub name: String,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceCollaboratorsInputs {
+    pub permissions: Vec<ExperienceCollaboratorPermission>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceLocalizationInputs {
+    pub locale: String,
+    pub title: String,
+    pub description: String,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SpatialVoiceInputs {
@@ -134,16 +270,18 @@ pub enum RobloxInputs {
     Experience(ExperienceInputs),
     ExperienceConfiguration(ExperienceConfigurationModel),
     ExperienceActivation(ExperienceActivationInputs),
-    ExperienceIcon(FileInputs),
-    ExperienceThumbnail(FileInputs),
+    ExperienceIcon(ExperienceIconInputs),
+    ExperienceThumbnail(ExperienceThumbnailInputs),
     ExperienceThumbnailOrder,
     Place(PlaceInputs),
-    PlaceFile(FileInputs),
+    PlaceFile(PlaceFileInputs),
+    PlaceFileBytes(PlaceFileBytesInputs),
     PlaceConfiguration(PlaceConfigurationModel),
     SocialLink(SocialLinkInputs),
     Product(ProductInputs),
-    ProductIcon(FileInputs),
+    ProductIcon(ProductIconInputs),
     Pass(PassInputs),
+    Subscription(SubscriptionInputs),
     Badge(BadgeInputs),
     BadgeIcon(FileInputs),
     ImageAsset(FileWithGroupIdInputs),
@@ -151,6 +289,10 @@ pub enum RobloxInputs {
     AssetAlias(AssetAliasInputs),
     SpatialVoice(SpatialVoiceInputs),
     Notification(NotificationInputs),
+    ExperienceLocalization(ExperienceLocalizationInputs),
+    ExperienceCollaborators(ExperienceCollaboratorsInputs),
+    ExperienceAvatar(UniverseAvatarModel),
+    ExperiencePrivateServers(PrivateServerSettingsModel),
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -158,6 +300,40 @@ pub enum RobloxInputs {
 pub struct ExperienceOutputs {
     pub asset_id: AssetId,
     pub start_place_id: AssetId,
+    /// Whether the experience was archived when it was imported. Only serialized when true so that
+    /// the outputs of existing experiences are unchanged.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_archived: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceThumbnailOrderOutputs {
+    /// The thumbnail order which was last applied to the experience.
+    #[serde(default)]
+    pub asset_ids: Vec<AssetId>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceConfigurationOutputs {
+    /// The experience the configuration was last applied to.
+    #[serde(default)]
+    pub experience_id: Option<AssetId>,
+    /// The configuration which was last applied to the experience.
+    #[serde(default)]
+    pub applied_configuration: Option<ExperienceConfigurationModel>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceConfigurationOutputs {
+    /// The place the configuration was last applied to.
+    #[serde(default)]
+    pub place_id: Option<AssetId>,
+    /// The configuration which was last applied to the place.
+    #[serde(default)]
+    pub applied_configuration: Option<PlaceConfigurationModel>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -172,10 +348,38 @@ pub struct NotificationOutputs {
     pub id: String,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceCollaboratorsOutputs {
+    /// The permissions which were last granted by Mantle.
+    pub permissions: Vec<ExperienceCollaboratorPermission>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperienceLocalizationOutputs {
+    pub locale: String,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaceFileOutputs {
     pub version: u64,
+    /// The place the file was last uploaded to.
+    #[serde(default)]
+    pub place_id: Option<AssetId>,
+    /// The hash of the file when it was last uploaded (see [`compute_file_hash`]).
+    #[serde(default)]
+    pub file_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BadgeIconOutputs {
+    pub asset_id: AssetId,
+    /// The hash of the icon file when it was last uploaded (see [`compute_file_hash`]).
+    #[serde(default)]
+    pub file_hash: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -185,6 +389,16 @@ pub struct ProductOutputs {
     pub product_id: AssetId,
 }
 
+impl ProductOutputs {
+    /// The URL of the product's public page, where it can be purchased.
+    pub fn purchase_url(&self) -> String {
+        format!(
+            "https://www.roblox.com/developer-products/{}",
+            self.asset_id
+        )
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PassOutputs {
@@ -192,6 +406,20 @@ pub struct PassOutputs {
     pub icon_asset_id: AssetId,
 }
 
+impl PassOutputs {
+    /// The URL of the pass's public store page, where it can be purchased.
+    pub fn shop_url(&self) -> String {
+        format!("https://www.roblox.com/game-pass/{}", self.asset_id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionOutputs {
+    pub asset_id: AssetId,
+    pub icon_asset_id: Option<AssetId>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetWithInitialIconOutputs {
@@ -216,25 +444,30 @@ pub struct AssetAliasOutputs {
 #[serde(rename_all = "camelCase")]
 pub enum RobloxOutputs {
     Experience(ExperienceOutputs),
-    ExperienceConfiguration,
+    ExperienceConfiguration(Box<ExperienceConfigurationOutputs>),
     ExperienceActivation,
     ExperienceIcon(AssetOutputs),
     ExperienceThumbnail(AssetOutputs),
-    ExperienceThumbnailOrder,
+    ExperienceThumbnailOrder(ExperienceThumbnailOrderOutputs),
     Place(AssetOutputs),
     PlaceFile(PlaceFileOutputs),
-    PlaceConfiguration,
+    PlaceConfiguration(PlaceConfigurationOutputs),
     SocialLink(AssetOutputs),
     Product(ProductOutputs),
     ProductIcon(AssetOutputs),
     Pass(PassOutputs),
+    Subscription(SubscriptionOutputs),
     Badge(AssetWithInitialIconOutputs),
-    BadgeIcon(AssetOutputs),
+    BadgeIcon(BadgeIconOutputs),
     ImageAsset(ImageAssetOutputs),
     AudioAsset(AssetOutputs),
     AssetAlias(AssetAliasOutputs),
     SpatialVoice,
     Notification(NotificationOutputs),
+    ExperienceLocalization(ExperienceLocalizationOutputs),
+    ExperienceCollaborators(ExperienceCollaboratorsOutputs),
+    ExperienceAvatar,
+    ExperiencePrivateServers,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -242,8 +475,193 @@ pub enum RobloxOutputs {
 pub struct RobloxResource {
     id: ResourceId,
     inputs: RobloxInputs,
+    #[serde(default, deserialize_with = "deserialize_outputs")]
     outputs: Option<RobloxOutputs>,
     dependencies: Vec<ResourceId>,
+    /// Arbitrary information about the deployment which created or last updated the resource. It
+    /// is not included in the inputs or outputs hashes, so it never causes the resource to change.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, String>,
+}
+
+/// Configuration and thumbnail order outputs used to be unit variants (e.g. `outputs:
+/// experienceConfiguration`). Upgrade them to empty outputs so that existing state files can still
+/// be loaded.
+fn deserialize_outputs<'de, D>(deserializer: D) -> Result<Option<RobloxOutputs>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut value = Value::deserialize(deserializer)?;
+    if let Value::String(variant) = &value {
+        if variant == "experienceConfiguration"
+            || variant == "placeConfiguration"
+            || variant == "experienceThumbnailOrder"
+        {
+            let mut mapping = Mapping::new();
+            mapping.insert(value.clone(), Value::Mapping(Mapping::new()));
+            value = Value::Mapping(mapping);
+        }
+    }
+    serde_yaml::from_value(value).map_err(serde::de::Error::custom)
+}
+
+const MIN_PAID_ACCESS_PRICE: u32 = 25;
+const MAX_PAID_ACCESS_PRICE: u32 = 1000;
+
+/// Roblox rejects paid access configurations without a valid price, so check them before they are
+/// sent. When paid access is disabled, the price is cleared so that a previous price is not kept.
+fn validate_paid_access(
+    experience_id: AssetId,
+    model: &mut ExperienceConfigurationModel,
+) -> Result<(), String> {
+    match model.is_for_sale {
+        None => return Ok(()),
+        Some(false) => {
+            model.price = None;
+            return Ok(());
+        }
+        Some(true) => {}
+    }
+
+    match model.price {
+        None | Some(0) => Err(format!(
+            "Experience {} has paid access enabled but no price. Set a price or disable paid access.",
+            experience_id
+        )),
+        Some(price) if !(MIN_PAID_ACCESS_PRICE..=MAX_PAID_ACCESS_PRICE).contains(&price) => {
+            Err(format!(
+                "Experience {} has a paid access price of {} Robux, but it must be between {} and {} Robux.",
+                experience_id, price, MIN_PAID_ACCESS_PRICE, MAX_PAID_ACCESS_PRICE
+            ))
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// Rejects private server prices which Roblox would ignore or reject. Free private servers must be
+/// sent with an explicit price of 0 (rather than no price) so that a previous price is removed.
+fn validate_private_servers(
+    experience_id: AssetId,
+    model: &mut ExperienceConfigurationModel,
+) -> Result<(), String> {
+    match (model.allow_private_servers, model.private_server_price) {
+        (None, _) => Ok(()),
+        (Some(false), Some(price)) if price > 0 => Err(format!(
+            "Experience {} has private servers disabled but a private server price of {} Robux. Enable private servers or remove the price.",
+            experience_id, price
+        )),
+        (Some(false), _) => {
+            model.private_server_price = None;
+            Ok(())
+        }
+        (Some(true), None) => Err(format!(
+            "Experience {} has private servers enabled but no price. Set a price or make private servers free.",
+            experience_id
+        )),
+        (Some(true), Some(_)) => Ok(()),
+    }
+}
+
+pub const MAX_PLACE_NAME_LENGTH: usize = 50;
+pub const MAX_PLACE_DESCRIPTION_LENGTH: usize = 1000;
+
+/// Returns a problem for each place configuration field which is longer than Roblox allows. The
+/// start place's name and description are also the experience's name and description.
+pub fn get_place_configuration_length_problems(
+    label: &str,
+    name: &str,
+    description: &str,
+) -> Vec<String> {
+    [
+        ("name", name, MAX_PLACE_NAME_LENGTH),
+        ("description", description, MAX_PLACE_DESCRIPTION_LENGTH),
+    ]
+    .iter()
+    .filter_map(|(field, value, max_length)| {
+        let length = value.chars().count();
+        (length > *max_length).then(|| {
+            format!(
+                "The {} of place {} is {} characters long, but it can be at most {} characters.",
+                field, label, length, max_length
+            )
+        })
+    })
+    .collect()
+}
+
+/// Refuses to switch a deployed experience to a different existing experience, since adopting the
+/// new experience would orphan the deployed one along with all of its resources.
+fn validate_experience_id_change(
+    experience: &ExperienceOutputs,
+    inputs: &ExperienceInputs,
+) -> Result<(), String> {
+    match inputs.asset_id {
+        Some(asset_id) if asset_id != experience.asset_id => Err(format!(
+            "The experience ID was changed from {} to {}. Switching the underlying experience is not supported: run `mantle destroy` to destroy the environment's resources in experience {}, then deploy again to use experience {}.",
+            experience.asset_id, asset_id, experience.asset_id, asset_id
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Link sharing and subscriber-only private servers only apply to experiences with private servers,
+/// so reject them if the applied configuration has private servers disabled.
+fn validate_private_server_settings(
+    experience_id: AssetId,
+    configuration: Option<&ExperienceConfigurationOutputs>,
+    settings: &PrivateServerSettingsModel,
+) -> Result<(), String> {
+    let allow_private_servers = configuration
+        .and_then(|outputs| outputs.applied_configuration.as_ref())
+        .and_then(|configuration| configuration.allow_private_servers);
+    if allow_private_servers == Some(false) && settings.requires_private_servers() {
+        return Err(format!(
+            "Experience {} has private servers disabled, so private server link sharing and subscriber-only private servers cannot be enabled.",
+            experience_id
+        ));
+    }
+    Ok(())
+}
+
+/// Roblox only applies avatar asset overrides when players can choose their own avatar type, so
+/// reject overrides which would otherwise be ignored.
+fn validate_universe_avatar(
+    experience_id: AssetId,
+    model: &UniverseAvatarModel,
+) -> Result<(), String> {
+    if !model.universe_avatar_asset_overrides.is_empty()
+        && !matches!(
+            model.universe_avatar_type,
+            Some(ExperienceAvatarType::PlayerChoice)
+        )
+    {
+        return Err(format!(
+            "Experience {} has avatar asset overrides but its avatar type is not player choice. Set the avatar type to player choice or remove the overrides.",
+            experience_id
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the names of the top-level fields whose live values differ from the values which were
+/// last applied. Fields which are missing from either model (e.g. because Roblox does not return
+/// them) are ignored.
+fn get_drifted_fields<T>(applied: &T, live: &T) -> Vec<String>
+where
+    T: Serialize,
+{
+    let applied = serde_yaml::to_value(applied).unwrap_or(Value::Null);
+    let live = serde_yaml::to_value(live).unwrap_or(Value::Null);
+    match (applied, live) {
+        (Value::Mapping(applied), Value::Mapping(live)) => applied
+            .iter()
+            .filter(
+                |(key, value)| matches!(live.get(key), Some(live_value) if live_value != *value),
+            )
+            .map(|(key, _)| key.as_str().unwrap_or_default().to_owned())
+            .collect(),
+        _ => Vec::new(),
+    }
 }
 
 impl RobloxResource {
@@ -253,6 +671,7 @@ impl RobloxResource {
             inputs,
             outputs: None,
             dependencies: dependencies.iter().map(|d| d.get_id()).collect(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -267,6 +686,7 @@ impl RobloxResource {
             inputs,
             outputs: Some(outputs),
             dependencies: dependencies.iter().map(|d| d.get_id()).collect(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -324,19 +744,215 @@ impl Resource<RobloxInputs, RobloxOutputs> for RobloxResource {
     fn set_outputs(&mut self, outputs: RobloxOutputs) {
         self.outputs = Some(outputs);
     }
+
+    fn get_metadata(&self) -> BTreeMap<String, String> {
+        self.metadata.clone()
+    }
+
+    fn set_metadata(&mut self, metadata: BTreeMap<String, String>) {
+        self.metadata = metadata;
+    }
 }
 
+type ResourceObserver = Box<dyn FnMut(ResourceEvent) + Send>;
+
 pub struct RobloxResourceManager {
     roblox_api: RobloxApi,
     roblox_cloud: Option<RbxCloud>,
+    open_cloud_api_key: Option<String>,
     project_path: PathBuf,
     payment_source: CreatorType,
+    observer: Option<Mutex<ResourceObserver>>,
+    image_upload_semaphore: Semaphore,
+    thumbnail_upload_pacer: UploadPacer,
+    /// Whether the ROBLOSECURITY cookie has been reloaded after an authorization failure. It is
+    /// only reloaded once so that a revoked cookie does not cause every operation to be retried.
+    reauthenticated: AtomicBool,
+    /// The experience's places keyed by ID, listed once so that checking each place does not
+    /// require its own request. Empty if the places could not be listed, and cleared whenever a
+    /// place is changed so that it is listed again the next time it is needed.
+    listed_places: AsyncMutex<Option<HashMap<AssetId, GetPlaceResponse>>>,
+    product_delete_behavior: ProductDeleteBehavior,
+    /// Whether experiences may be archived when they are deleted. Archiving an experience takes it
+    /// offline, so it must be explicitly allowed.
+    allow_experience_destroy: bool,
+}
+
+/// How developer products are retired when they are deleted, since Roblox does not allow them to
+/// be deleted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProductDeleteBehavior {
+    /// Takes the product off sale, falling back to `Rename` if Roblox does not support it.
+    #[default]
+    Deactivate,
+    /// Renames the product to `zzz_DEPRECATED(<date-time>)` and clears its description and price.
+    Rename,
+}
+
+/// The default number of images which may be uploaded at the same time.
+pub const DEFAULT_IMAGE_UPLOAD_CONCURRENCY: usize = 4;
+
+/// The default number of thumbnails which may be uploaded per minute.
+pub const DEFAULT_THUMBNAIL_UPLOADS_PER_MINUTE: usize = 10;
+
+const RATE_LIMIT_WINDOW: time::Duration = time::Duration::from_secs(60);
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+const PLACE_VERSION_POLL_INITIAL_DELAY: time::Duration = time::Duration::from_millis(500);
+const PLACE_VERSION_POLL_TIMEOUT: time::Duration = time::Duration::from_secs(30);
+
+/// Limits how many uploads are started within any one-minute window so that bulk uploads stay
+/// within Roblox's per-minute rate limits.
+struct UploadPacer {
+    per_minute: usize,
+    started_at: Mutex<VecDeque<Instant>>,
+}
+
+impl UploadPacer {
+    fn new(per_minute: usize) -> Self {
+        Self {
+            per_minute: per_minute.max(1),
+            started_at: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Waits until another upload can be started without exceeding the budget.
+    async fn wait(&self) {
+        loop {
+            let delay = {
+                let mut started_at = self.started_at.lock().unwrap();
+                let now = Instant::now();
+                while matches!(started_at.front(), Some(t) if now.duration_since(*t) >= RATE_LIMIT_WINDOW)
+                {
+                    started_at.pop_front();
+                }
+                if started_at.len() < self.per_minute {
+                    started_at.push_back(now);
+                    return;
+                }
+                RATE_LIMIT_WINDOW - now.duration_since(*started_at.front().unwrap())
+            };
+
+            logger::log(format!(
+                "Pacing uploads to {} per minute; waiting {}s",
+                self.per_minute,
+                delay.as_secs() + 1
+            ));
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+fn is_rate_limited(error: &RobloxApiError) -> bool {
+    matches!(error, RobloxApiError::Roblox { status_code, .. } if status_code.as_u16() == 429)
+}
+
+/// Formats the error returned when the live resource which is about to be deleted does not match
+/// the state file, so that a stale state file does not cause the wrong resource to be deleted.
+fn stale_state_error(resource: String, reason: String) -> String {
+    format!(
+        "Refusing to delete {}: {}. The state file may be out of date; remove the resource from the state file if it was deleted outside of Mantle.",
+        resource, reason
+    )
+}
+
+fn get_positive_env_var(name: &str, default: usize) -> Result<usize, String> {
+    match env::var(name) {
+        Ok(v) => match v.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("{} must be a positive integer, got {}", name, v)),
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+/// Logs the progress of a place file upload every 10%, so that large uploads do not appear to
+/// have stalled.
+fn log_place_upload_progress() -> UploadProgressCallback {
+    let reported_percent = AtomicU64::new(0);
+    Arc::new(move |sent, total| {
+        let percent = (sent * 100).checked_div(total).unwrap_or(100);
+        let step = percent / 10 * 10;
+        if step > reported_percent.fetch_max(step, Ordering::SeqCst) {
+            logger::log(format!(
+                "Uploaded {}% of place file ({} of {} bytes)",
+                step, sent, total
+            ));
+        }
+    })
+}
+
+/// Explains a failure to configure an experience. Roblox only allows paid access for creators who
+/// meet its eligibility requirements, and rejects the whole configuration otherwise.
+fn get_configure_experience_error(
+    experience_id: AssetId,
+    model: &ExperienceConfigurationModel,
+    error: RobloxApiError,
+) -> String {
+    match error {
+        RobloxApiError::Roblox {
+            status_code,
+            reason,
+        } if model.is_for_sale == Some(true) && status_code.as_u16() == 403 => format!(
+            "Experience {} has paid access enabled, but its creator is not eligible to sell access to experiences: {}. Disable paid access or check the creator's eligibility on the Creator Dashboard.",
+            experience_id, reason
+        ),
+        error => error.into(),
+    }
+}
+
+/// Whether an operation's error may have been caused by Roblox rejecting the ROBLOSECURITY cookie.
+fn is_authorization_error(error: &str) -> bool {
+    error.contains(&RobloxApiError::Authorization.to_string())
+        || error.contains(&RobloxApiError::AuthorizationExpired.to_string())
+        || [StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN]
+            .iter()
+            .any(|status_code| error.contains(&format!("Roblox error ({})", status_code)))
+}
+
+fn changes_listed_places(inputs: &RobloxInputs) -> bool {
+    matches!(
+        inputs,
+        RobloxInputs::Place(_)
+            | RobloxInputs::PlaceFile(_)
+            | RobloxInputs::PlaceFileBytes(_)
+            | RobloxInputs::PlaceConfiguration(_)
+    )
+}
+
+fn is_image_upload(inputs: &RobloxInputs) -> bool {
+    matches!(
+        inputs,
+        RobloxInputs::ExperienceIcon(_)
+            | RobloxInputs::ExperienceThumbnail(ExperienceThumbnailInputs { asset_id: None, .. })
+            | RobloxInputs::ProductIcon(ProductIconInputs {
+                file_path: Some(_),
+                ..
+            })
+            | RobloxInputs::BadgeIcon(_)
+            | RobloxInputs::ImageAsset(_)
+    )
+}
+
+/// Explains a failure to get the experience's start place. A missing start place usually means that
+/// the experience itself was deleted.
+fn get_start_place_error(start_place_id: AssetId, error: RobloxApiError) -> String {
+    match error {
+        RobloxApiError::Roblox { status_code, .. } if status_code.as_u16() == 404 => format!(
+            "start place {} not found; the experience may have been deleted — remove it from state and re-create",
+            start_place_id
+        ),
+        error => error.into(),
+    }
 }
 
 impl RobloxResourceManager {
     pub async fn new(project_path: &Path, payment_source: CreatorType) -> Result<Self, String> {
         let roblox_auth = RobloxAuth::new().await?;
-        let roblox_api = RobloxApi::new(roblox_auth)?;
+        let retry_budget =
+            get_positive_env_var("MANTLE_RETRY_BUDGET", DEFAULT_RETRY_BUDGET as usize)?;
+        let roblox_api = RobloxApi::new(roblox_auth)?
+            .with_retry_budget(retry_budget.min(u32::MAX as usize) as u32);
         roblox_api.validate_auth().await?;
 
         let open_cloud_api_key = match env::var("MANTLE_OPEN_CLOUD_API_KEY") {
@@ -347,23 +963,374 @@ impl RobloxResourceManager {
             Err(_) => None,
         };
 
-        let roblox_cloud = open_cloud_api_key.map(|api_key| RbxCloud::new(&api_key));
+        let roblox_cloud = open_cloud_api_key
+            .as_ref()
+            .map(|api_key| RbxCloud::new(api_key));
+
+        let image_upload_concurrency = get_positive_env_var(
+            "MANTLE_IMAGE_UPLOAD_CONCURRENCY",
+            DEFAULT_IMAGE_UPLOAD_CONCURRENCY,
+        )?;
+        let thumbnail_uploads_per_minute = get_positive_env_var(
+            "MANTLE_THUMBNAIL_UPLOADS_PER_MINUTE",
+            DEFAULT_THUMBNAIL_UPLOADS_PER_MINUTE,
+        )?;
+
+        let mut manager = Self::with_roblox_api(roblox_api, project_path, payment_source);
+        manager.roblox_cloud = roblox_cloud;
+        manager.open_cloud_api_key = open_cloud_api_key;
+        manager.set_image_upload_concurrency(image_upload_concurrency);
+        manager.set_thumbnail_uploads_per_minute(thumbnail_uploads_per_minute);
+        Ok(manager)
+    }
 
-        Ok(Self {
+    /// Creates a manager which makes its requests with an existing client, without Open Cloud.
+    fn with_roblox_api(
+        roblox_api: RobloxApi,
+        project_path: &Path,
+        payment_source: CreatorType,
+    ) -> Self {
+        Self {
             roblox_api,
-            roblox_cloud,
+            roblox_cloud: None,
+            open_cloud_api_key: None,
             project_path: project_path.to_path_buf(),
             payment_source,
-        })
+            observer: None,
+            image_upload_semaphore: Semaphore::new(DEFAULT_IMAGE_UPLOAD_CONCURRENCY),
+            thumbnail_upload_pacer: UploadPacer::new(DEFAULT_THUMBNAIL_UPLOADS_PER_MINUTE),
+            reauthenticated: AtomicBool::new(false),
+            listed_places: AsyncMutex::new(None),
+            product_delete_behavior: ProductDeleteBehavior::default(),
+            allow_experience_destroy: false,
+        }
+    }
+
+    /// Sets a function which will be called with progress events as each resource is created,
+    /// updated, or deleted.
+    pub fn set_observer<F>(&mut self, observer: F)
+    where
+        F: FnMut(ResourceEvent) + Send + 'static,
+    {
+        self.observer = Some(Mutex::new(Box::new(observer)));
+    }
+
+    /// Sets the maximum number of images (icons, thumbnails, and image assets) which may be
+    /// uploaded at the same time. Defaults to [`DEFAULT_IMAGE_UPLOAD_CONCURRENCY`], or the value of
+    /// the `MANTLE_IMAGE_UPLOAD_CONCURRENCY` environment variable.
+    pub fn set_image_upload_concurrency(&mut self, concurrency: usize) {
+        self.image_upload_semaphore = Semaphore::new(concurrency.max(1));
+    }
+
+    /// Sets the maximum number of thumbnails which may be uploaded per minute. Defaults to
+    /// [`DEFAULT_THUMBNAIL_UPLOADS_PER_MINUTE`], or the value of the
+    /// `MANTLE_THUMBNAIL_UPLOADS_PER_MINUTE` environment variable.
+    pub fn set_thumbnail_uploads_per_minute(&mut self, per_minute: usize) {
+        self.thumbnail_upload_pacer = UploadPacer::new(per_minute);
+    }
+
+    /// Sets how developer products are retired when they are deleted. Defaults to
+    /// [`ProductDeleteBehavior::Deactivate`].
+    pub fn set_product_delete_behavior(&mut self, behavior: ProductDeleteBehavior) {
+        self.product_delete_behavior = behavior;
+    }
+
+    /// Sets whether experiences may be archived when they are deleted. Defaults to `false`, in
+    /// which case deleting an experience fails without changing it.
+    pub fn set_allow_experience_destroy(&mut self, allow: bool) {
+        self.allow_experience_destroy = allow;
+    }
+
+    /// Restarts the experience's servers so that players get the latest published places. This is
+    /// done once after a deployment rather than by each place file so that servers are not
+    /// restarted repeatedly.
+    pub async fn restart_experience_servers(&self, experience_id: AssetId) -> Result<(), String> {
+        let open_cloud_api_key = self.open_cloud_api_key.as_deref().ok_or_else(|| {
+            "Restarting servers requires an Open Cloud API key. Set the MANTLE_OPEN_CLOUD_API_KEY environment variable.".to_owned()
+        })?;
+
+        match self
+            .roblox_api
+            .restart_experience_servers(experience_id, open_cloud_api_key)
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(RobloxApiError::Roblox {
+                status_code,
+                reason,
+            }) if matches!(status_code.as_u16(), 401 | 403) => Err(format!(
+                "Unable to restart servers for experience {}: the Open Cloud API key does not have permission to restart its servers. Grant the key write access to the experience.\n\t{}",
+                experience_id, reason
+            )),
+            Err(e) => Err(format!(
+                "Unable to restart servers for experience {}: {}",
+                experience_id, e
+            )),
+        }
     }
 
     fn get_path(&self, file: String) -> PathBuf {
         self.project_path.join(file)
     }
+
+    /// Gets the path of an image which is either a file in the project or was downloaded from a
+    /// URL.
+    fn get_image_path(
+        &self,
+        description: &str,
+        file_path: Option<String>,
+        url: Option<String>,
+    ) -> Result<PathBuf, String> {
+        match (file_path, url) {
+            (Some(file_path), None) => Ok(self.get_path(file_path)),
+            (None, Some(url)) => Ok(get_remote_image_path(&url)),
+            _ => Err(format!(
+                "{} must have exactly one of a file path or a URL",
+                description
+            )),
+        }
+    }
+
+    /// Gets the experience's places, which are only listed again once they have been changed.
+    async fn get_listed_places(
+        &self,
+        experience_id: AssetId,
+    ) -> RobloxApiResult<HashMap<AssetId, GetPlaceResponse>> {
+        let mut listed_places = self.listed_places.lock().await;
+        if let Some(places) = listed_places.as_ref() {
+            return Ok(places.clone());
+        }
+
+        let places: HashMap<_, _> = self
+            .roblox_api
+            .list_places(experience_id)
+            .await?
+            .into_iter()
+            .map(|place| (place.id, place))
+            .collect();
+        *listed_places = Some(places.clone());
+        Ok(places)
+    }
+
+    /// Gets a place from the experience's place list when an `experience_id` is provided. Falls
+    /// back to getting the place on its own if it was not listed or the places could not be
+    /// listed.
+    async fn get_listed_place(
+        &self,
+        experience_id: Option<AssetId>,
+        place_id: AssetId,
+    ) -> RobloxApiResult<GetPlaceResponse> {
+        let listed_place = match experience_id {
+            Some(experience_id) => match self.get_listed_places(experience_id).await {
+                Ok(places) => places.get(&place_id).cloned(),
+                Err(e) => {
+                    info!("Unable to list places, getting them one at a time: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        match listed_place {
+            Some(place) => Ok(place),
+            None => self.roblox_api.get_place(place_id).await,
+        }
+    }
+
+    /// Clears the listed places so that changes to the places are seen the next time they are
+    /// listed.
+    async fn invalidate_listed_places(&self) {
+        *self.listed_places.lock().await = None;
+    }
+
+    /// Returns the place's saved version once it is newer than `previous_version`. Roblox sometimes
+    /// returns the prior version immediately after a place is published, so the place is re-read
+    /// with exponential backoff. If the version has not changed before the timeout elapses, the
+    /// latest version is returned with a warning rather than failing the deployment.
+    async fn get_published_place_version(
+        &self,
+        place_id: AssetId,
+        previous_version: u64,
+    ) -> Result<u64, String> {
+        let started_at = Instant::now();
+        let mut delay = PLACE_VERSION_POLL_INITIAL_DELAY;
+        loop {
+            let GetPlaceResponse {
+                current_saved_version,
+                ..
+            } = self.roblox_api.get_place(place_id).await?;
+            if current_saved_version > previous_version {
+                return Ok(current_saved_version);
+            }
+
+            if started_at.elapsed() + delay > PLACE_VERSION_POLL_TIMEOUT {
+                logger::log("");
+                logger::log(Paint::yellow(format!(
+                    "Roblox still reports version {} of place {} after publishing. The recorded version may not match the uploaded file.",
+                    current_saved_version, place_id
+                )));
+                return Ok(current_saved_version);
+            }
+
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+        }
+    }
+
+    /// Returns a function which checks whether a permission belongs to the experience's owner, so
+    /// that the owner's access is never removed.
+    async fn get_experience_owner_check(
+        &self,
+        experience_id: AssetId,
+    ) -> Result<impl Fn(&ExperienceCollaboratorPermission) -> bool, String> {
+        let GetExperienceResponse {
+            creator_type,
+            creator_target_id,
+            ..
+        } = self.roblox_api.get_experience(experience_id).await?;
+
+        Ok(move |permission: &ExperienceCollaboratorPermission| {
+            matches!(creator_type, CreatorType::User)
+                && permission.subject_type == ExperienceCollaboratorSubjectType::User
+                && permission.subject_id == creator_target_id
+        })
+    }
+
+    /// Grants each of the permissions and removes any other permissions from the experience, except
+    /// for the owner's.
+    async fn sync_experience_collaborators(
+        &self,
+        experience_id: AssetId,
+        permissions: &[ExperienceCollaboratorPermission],
+    ) -> Result<(), String> {
+        let is_owner = self.get_experience_owner_check(experience_id).await?;
+        let live_permissions = self
+            .roblox_api
+            .list_experience_permissions(experience_id)
+            .await?;
+
+        let added_permissions = permissions
+            .iter()
+            .filter(|permission| !live_permissions.contains(permission))
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed_permissions = live_permissions
+            .iter()
+            .filter(|permission| !permissions.contains(permission) && !is_owner(permission))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !added_permissions.is_empty() {
+            logger::log(format!(
+                "Granting {} permission(s)",
+                added_permissions.len()
+            ));
+            self.roblox_api
+                .set_experience_permissions(experience_id, &added_permissions)
+                .await?;
+        }
+        if !removed_permissions.is_empty() {
+            logger::log(format!(
+                "Removing {} permission(s)",
+                removed_permissions.len()
+            ));
+            self.roblox_api
+                .remove_experience_permissions(experience_id, &removed_permissions)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the value of a `file_hash` input: the SHA-256 digest of the data, encoded as lowercase
+/// hex.
+pub fn compute_hash(data: &[u8]) -> String {
+    let digest = Sha256::digest(data);
+    format!("{:x}", digest)
+}
+
+/// Computes the value of a `file_hash` input for the file at the given path (see
+/// [`compute_hash`]).
+pub fn compute_file_hash(file_path: &Path) -> Result<String, String> {
+    let buffer = fs::read(file_path).map_err(|e| {
+        format!(
+            "Failed to read file {} for hashing: {}",
+            file_path.display(),
+            e
+        )
+    })?;
+    Ok(compute_hash(&buffer))
+}
+
+/// Whether the file with this hash is the one which was last uploaded to the place, in which case
+/// it does not need to be uploaded again.
+fn is_place_file_uploaded(outputs: &PlaceFileOutputs, place_id: AssetId, file_hash: &str) -> bool {
+    outputs.place_id == Some(place_id) && outputs.file_hash.as_deref() == Some(file_hash)
 }
 
 #[async_trait]
 impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
+    fn report(&self, event: ResourceEvent) {
+        if let Some(observer) = &self.observer {
+            let mut observer = observer.lock().unwrap();
+            observer(event);
+        }
+    }
+
+    fn can_run_concurrently(&self, inputs: &RobloxInputs) -> bool {
+        is_image_upload(inputs)
+    }
+
+    async fn recover(&self, error: &str) -> bool {
+        // Only authorization failures (e.g. the cookie expiring during a long deployment) can be
+        // recovered from
+        if !is_authorization_error(error)
+            || self.roblox_api.validate_auth().await.is_ok()
+            || self.reauthenticated.swap(true, Ordering::SeqCst)
+        {
+            return false;
+        }
+
+        logger::log("");
+        logger::log(Paint::yellow(
+            "Roblox rejected the ROBLOSECURITY cookie. Reloading it in case it was refreshed.",
+        ));
+        match self.roblox_api.reauthenticate().await {
+            Ok(()) => {
+                logger::log("Reloaded the ROBLOSECURITY cookie. Retrying the operation.");
+                true
+            }
+            Err(e) => {
+                logger::log(Paint::red(e.to_string()));
+                false
+            }
+        }
+    }
+
+    fn get_changed_dependency_outputs(
+        &self,
+        inputs: &RobloxInputs,
+        outputs: &RobloxOutputs,
+        dependency_outputs: &RobloxOutputs,
+    ) -> Option<RobloxOutputs> {
+        match (inputs, outputs, dependency_outputs) {
+            // Keep the experience's start place up to date when another place becomes the start
+            // place so that it is not used for later operations (e.g. removing the icon)
+            (
+                RobloxInputs::Place(PlaceInputs { is_start: true, .. }),
+                RobloxOutputs::Place(place),
+                RobloxOutputs::Experience(experience),
+            ) if place.asset_id != experience.start_place_id => {
+                Some(RobloxOutputs::Experience(ExperienceOutputs {
+                    start_place_id: place.asset_id,
+                    ..experience.clone()
+                }))
+            }
+            _ => None,
+        }
+    }
+
     async fn get_create_price(
         &self,
         inputs: RobloxInputs,
@@ -414,7 +1381,37 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
         dependency_outputs: Vec<RobloxOutputs>,
         price: Option<u32>,
     ) -> Result<RobloxOutputs, String> {
+        let _permit = if is_image_upload(&inputs) {
+            Some(
+                self.image_upload_semaphore
+                    .acquire()
+                    .await
+                    .map_err(|e| format!("Failed to acquire image upload permit: {}", e))?,
+            )
+        } else {
+            None
+        };
+        if changes_listed_places(&inputs) {
+            self.invalidate_listed_places().await;
+        }
+
         match inputs {
+            RobloxInputs::Experience(ExperienceInputs {
+                asset_id: Some(asset_id),
+                ..
+            }) => {
+                let GetExperienceResponse {
+                    root_place_id,
+                    is_archived,
+                    ..
+                } = self.roblox_api.get_experience(asset_id).await?;
+
+                Ok(RobloxOutputs::Experience(ExperienceOutputs {
+                    asset_id,
+                    start_place_id: root_place_id,
+                    is_archived,
+                }))
+            }
             RobloxInputs::Experience(inputs) => {
                 let CreateExperienceResponse {
                     universe_id,
@@ -424,16 +1421,26 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                 Ok(RobloxOutputs::Experience(ExperienceOutputs {
                     asset_id: universe_id,
                     start_place_id: root_place_id,
+                    is_archived: false,
                 }))
             }
-            RobloxInputs::ExperienceConfiguration(inputs) => {
+            RobloxInputs::ExperienceConfiguration(mut inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
+                validate_paid_access(experience.asset_id, &mut inputs)?;
+                validate_private_servers(experience.asset_id, &mut inputs)?;
+
                 self.roblox_api
                     .configure_experience(experience.asset_id, &inputs)
-                    .await?;
-
-                Ok(RobloxOutputs::ExperienceConfiguration)
+                    .await
+                    .map_err(|e| get_configure_experience_error(experience.asset_id, &inputs, e))?;
+
+                Ok(RobloxOutputs::ExperienceConfiguration(Box::new(
+                    ExperienceConfigurationOutputs {
+                        experience_id: Some(experience.asset_id),
+                        applied_configuration: Some(inputs),
+                    },
+                )))
             }
             RobloxInputs::ExperienceActivation(inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
@@ -447,9 +1454,10 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
             RobloxInputs::ExperienceIcon(inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
+                let image_path = self.get_image_path("Icons", inputs.file_path, inputs.url)?;
                 let UploadImageResponse { target_id } = self
                     .roblox_api
-                    .upload_icon(experience.asset_id, self.get_path(inputs.file_path))
+                    .upload_icon(experience.asset_id, image_path)
                     .await?;
 
                 Ok(RobloxOutputs::ExperienceIcon(AssetOutputs {
@@ -459,10 +1467,44 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
             RobloxInputs::ExperienceThumbnail(inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
-                let UploadImageResponse { target_id } = self
-                    .roblox_api
-                    .upload_thumbnail(experience.asset_id, self.get_path(inputs.file_path))
-                    .await?;
+                let image_path = match inputs.asset_id {
+                    Some(asset_id) if inputs.file_path.is_none() && inputs.url.is_none() => {
+                        let UploadImageResponse { target_id } = self
+                            .roblox_api
+                            .add_thumbnail_asset(experience.asset_id, asset_id)
+                            .await?;
+                        return Ok(RobloxOutputs::ExperienceThumbnail(AssetOutputs {
+                            asset_id: target_id,
+                        }));
+                    }
+                    None => self.get_image_path("Thumbnails", inputs.file_path, inputs.url)?,
+                    Some(_) => return Err(
+                        "Thumbnails must have exactly one of a file path, a URL, or an asset ID"
+                            .to_owned(),
+                    ),
+                };
+
+                // Uploads are paced to stay within the rate limit, but if the limit is hit anyway
+                // (e.g. because of uploads from another process) wait for it to reset and retry
+                let mut attempt = 0;
+                let UploadImageResponse { target_id } = loop {
+                    self.thumbnail_upload_pacer.wait().await;
+                    match self
+                        .roblox_api
+                        .upload_thumbnail(experience.asset_id, image_path.clone())
+                        .await
+                    {
+                        Err(e) if is_rate_limited(&e) && attempt < MAX_RATE_LIMIT_RETRIES => {
+                            logger::log(format!(
+                                "Rate limited while uploading thumbnail; retrying in {}s",
+                                RATE_LIMIT_WINDOW.as_secs()
+                            ));
+                            tokio::time::sleep(RATE_LIMIT_WINDOW).await;
+                            attempt += 1;
+                        }
+                        result => break result?,
+                    }
+                };
 
                 Ok(RobloxOutputs::ExperienceThumbnail(AssetOutputs {
                     asset_id: target_id,
@@ -470,28 +1512,77 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
             }
             RobloxInputs::ExperienceThumbnailOrder => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
-                let thumbnails =
-                    all_outputs!(dependency_outputs, RobloxOutputs::ExperienceThumbnail);
+                let asset_ids =
+                    all_outputs!(dependency_outputs, RobloxOutputs::ExperienceThumbnail)
+                        .iter()
+                        .map(|t| t.asset_id)
+                        .collect::<Vec<_>>();
+
+                // Catch references to thumbnails which no longer exist before Roblox rejects the
+                // whole order
+                let uploaded_thumbnails = self
+                    .roblox_api
+                    .get_experience_thumbnails(experience.asset_id)
+                    .await?;
+                if let Some(asset_id) = asset_ids
+                    .iter()
+                    .find(|asset_id| !uploaded_thumbnails.iter().any(|t| t.id == **asset_id))
+                {
+                    return Err(format!(
+                        "Thumbnail {} is not uploaded to experience {}",
+                        asset_id, experience.asset_id
+                    ));
+                }
 
                 self.roblox_api
-                    .set_experience_thumbnail_order(
-                        experience.asset_id,
-                        &thumbnails.iter().map(|t| t.asset_id).collect::<Vec<_>>(),
-                    )
+                    .set_experience_thumbnail_order(experience.asset_id, &asset_ids)
                     .await?;
 
-                Ok(RobloxOutputs::ExperienceThumbnailOrder)
+                Ok(RobloxOutputs::ExperienceThumbnailOrder(
+                    ExperienceThumbnailOrderOutputs { asset_ids },
+                ))
             }
             RobloxInputs::Place(inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
                 let asset_id = if inputs.is_start {
+                    // The start place cannot be created or deleted on its own, so make sure it still
+                    // exists before carrying on with the rest of the deployment.
+                    if let Err(e) = self.roblox_api.get_place(experience.start_place_id).await {
+                        return Err(get_start_place_error(experience.start_place_id, e));
+                    }
+
                     experience.start_place_id
                 } else {
-                    self.roblox_api
-                        .create_place(experience.asset_id)
-                        .await?
-                        .place_id
+                    let place_id = match inputs.template_place_id {
+                        Some(template_place_id) => {
+                            // Check that the template can be accessed so that a clear error is
+                            // returned instead of Roblox's generic one
+                            self.roblox_api
+                                .get_place(template_place_id)
+                                .await
+                                .map_err(|e| {
+                                    format!(
+                                        "Unable to access template place {}. The template must be a place in an experience you can edit: {}",
+                                        template_place_id, e
+                                    )
+                                })?;
+                            self.roblox_api
+                                .create_place_from_template(experience.asset_id, template_place_id)
+                                .await?
+                                .place_id
+                        }
+                        None => {
+                            self.roblox_api
+                                .create_place(experience.asset_id)
+                                .await?
+                                .place_id
+                        }
+                    };
+                    if let Some(name) = &inputs.name {
+                        self.roblox_api.set_place_name(place_id, name).await?;
+                    }
+                    place_id
                 };
 
                 Ok(RobloxOutputs::Place(AssetOutputs { asset_id }))
@@ -500,12 +1591,46 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                 let place = single_output!(dependency_outputs, RobloxOutputs::Place);
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
-                if let Some(roblox_cloud) = &self.roblox_cloud {
+                let file_path = match (inputs.file_path, inputs.version) {
+                    (Some(file_path), None) => file_path,
+                    (None, Some(version)) => {
+                        let previous_version = self
+                            .roblox_api
+                            .get_place(place.asset_id)
+                            .await?
+                            .current_saved_version;
+                        self.roblox_api
+                            .rollback_place(place.asset_id, version)
+                            .await?;
+                        let version = self
+                            .get_published_place_version(place.asset_id, previous_version)
+                            .await?;
+
+                        return Ok(RobloxOutputs::PlaceFile(PlaceFileOutputs {
+                            version,
+                            place_id: Some(place.asset_id),
+                            file_hash: None,
+                        }));
+                    }
+                    _ => {
+                        return Err(
+                            "Place files must have exactly one of a file path or a version"
+                                .to_owned(),
+                        )
+                    }
+                };
+
+                let file_hash = compute_file_hash(&self.get_path(file_path.clone()))?;
+
+                // The Open Cloud publish API detects the format from the file's extension, so
+                // place files with an overridden format are always uploaded with the ROBLOSECURITY
+                // cookie.
+                if let (Some(roblox_cloud), None) = (&self.roblox_cloud, inputs.file_format) {
                     let response = roblox_cloud
                         .experience(UniverseId(experience.asset_id), PlaceId(place.asset_id))
                         .publish(
                             &self
-                                .get_path(inputs.file_path)
+                                .get_path(file_path)
                                 .into_os_string()
                                 .into_string()
                                 .unwrap(),
@@ -516,29 +1641,81 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
 
                     Ok(RobloxOutputs::PlaceFile(PlaceFileOutputs {
                         version: response.version_number,
+                        place_id: Some(place.asset_id),
+                        file_hash: Some(file_hash),
                     }))
                 } else {
+                    // Read the version before uploading (rather than using the version in the
+                    // state) so that publishes made outside of Mantle are accounted for.
+                    let previous_version = self
+                        .roblox_api
+                        .get_place(place.asset_id)
+                        .await?
+                        .current_saved_version;
                     self.roblox_api
-                        .upload_place(self.get_path(inputs.file_path), place.asset_id)
+                        .upload_place_with_progress(
+                            self.get_path(file_path),
+                            place.asset_id,
+                            inputs.file_format,
+                            Some(log_place_upload_progress()),
+                        )
+                        .await?;
+                    let version = self
+                        .get_published_place_version(place.asset_id, previous_version)
                         .await?;
-                    let GetPlaceResponse {
-                        current_saved_version,
-                        ..
-                    } = self.roblox_api.get_place(place.asset_id).await?;
 
                     Ok(RobloxOutputs::PlaceFile(PlaceFileOutputs {
-                        version: current_saved_version,
+                        version,
+                        place_id: Some(place.asset_id),
+                        file_hash: Some(file_hash),
                     }))
                 }
             }
+            // The Open Cloud publish API only accepts a file path, so in-memory place files are
+            // always uploaded with the ROBLOSECURITY cookie.
+            RobloxInputs::PlaceFileBytes(inputs) => {
+                let place = single_output!(dependency_outputs, RobloxOutputs::Place);
+
+                let previous_version = self
+                    .roblox_api
+                    .get_place(place.asset_id)
+                    .await?
+                    .current_saved_version;
+                self.roblox_api
+                    .upload_place_bytes(&inputs.bytes, place.asset_id, inputs.is_xml)
+                    .await?;
+                let version = self
+                    .get_published_place_version(place.asset_id, previous_version)
+                    .await?;
+
+                Ok(RobloxOutputs::PlaceFile(PlaceFileOutputs {
+                    version,
+                    place_id: Some(place.asset_id),
+                    file_hash: Some(compute_hash(&inputs.bytes)),
+                }))
+            }
             RobloxInputs::PlaceConfiguration(inputs) => {
                 let place = single_output!(dependency_outputs, RobloxOutputs::Place);
 
+                let problems = get_place_configuration_length_problems(
+                    &place.asset_id.to_string(),
+                    &inputs.name,
+                    &inputs.description,
+                );
+                if !problems.is_empty() {
+                    return Err(problems.join("\n"));
+                }
+
                 self.roblox_api
                     .configure_place(place.asset_id, &inputs)
                     .await?;
 
-                Ok(RobloxOutputs::PlaceConfiguration)
+                Ok(RobloxOutputs::PlaceConfiguration(
+                    PlaceConfigurationOutputs {
+                        place_id: Some(place.asset_id),
+                        applied_configuration: Some(inputs),
+                    },
+                ))
             }
             RobloxInputs::SocialLink(inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
@@ -558,12 +1735,31 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
             RobloxInputs::ProductIcon(inputs) => {
                 let product = single_output!(dependency_outputs, RobloxOutputs::Product);
 
+                let file_path = match (inputs.file_path, inputs.asset_id) {
+                    (None, Some(asset_id)) => {
+                        let experience =
+                            single_output!(dependency_outputs, RobloxOutputs::Experience);
+                        self.roblox_api
+                            .set_developer_product_icon(
+                                experience.asset_id,
+                                product.asset_id,
+                                asset_id,
+                            )
+                            .await?;
+                        return Ok(RobloxOutputs::ProductIcon(AssetOutputs { asset_id }));
+                    }
+                    (Some(file_path), None) => file_path,
+                    _ => {
+                        return Err(
+                            "Product icons must have exactly one of a file path or an asset ID"
+                                .to_owned(),
+                        )
+                    }
+                };
+
                 let CreateDeveloperProductIconResponse { image_asset_id } = self
                     .roblox_api
-                    .create_developer_product_icon(
-                        product.asset_id,
-                        self.get_path(inputs.file_path),
-                    )
+                    .create_developer_product_icon(product.asset_id, self.get_path(file_path))
                     .await?;
 
                 Ok(RobloxOutputs::ProductIcon(AssetOutputs {
@@ -577,12 +1773,26 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     .roblox_api
                     .create_developer_product(
                         experience.asset_id,
-                        inputs.name,
+                        inputs.name.clone(),
                         inputs.price,
-                        inputs.description,
+                        inputs.description.clone(),
                     )
                     .await?;
 
+                // Regional prices cannot be set when the product is created
+                if inputs.regional_prices.is_some() {
+                    self.roblox_api
+                        .update_developer_product(
+                            experience.asset_id,
+                            id,
+                            inputs.name,
+                            inputs.price,
+                            inputs.description,
+                            inputs.regional_prices.as_ref(),
+                        )
+                        .await?;
+                }
+
                 let GetDeveloperProductResponse { id: product_id } =
                     self.roblox_api.get_developer_product(id).await?;
 
@@ -622,6 +1832,30 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     icon_asset_id: icon_image_asset_id,
                 }))
             }
+            RobloxInputs::Subscription(inputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                let CreateDeveloperSubscriptionResponse { id } = self
+                    .roblox_api
+                    .create_developer_subscription(
+                        experience.asset_id,
+                        inputs.name,
+                        inputs.description,
+                        inputs.price,
+                        inputs.period,
+                        self.get_path(inputs.icon_file_path),
+                    )
+                    .await?;
+                let GetDeveloperSubscriptionResponse { image_asset_id, .. } = self
+                    .roblox_api
+                    .get_developer_subscription(experience.asset_id, id)
+                    .await?;
+
+                Ok(RobloxOutputs::Subscription(SubscriptionOutputs {
+                    asset_id: id,
+                    icon_asset_id: image_asset_id,
+                }))
+            }
             RobloxInputs::Badge(inputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
@@ -642,11 +1876,12 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     initial_icon_asset_id: icon_image_id,
                 }))
             }
-            RobloxInputs::BadgeIcon(_) => {
+            RobloxInputs::BadgeIcon(inputs) => {
                 let badge = single_output!(dependency_outputs, RobloxOutputs::Badge);
 
-                Ok(RobloxOutputs::BadgeIcon(AssetOutputs {
+                Ok(RobloxOutputs::BadgeIcon(BadgeIconOutputs {
                     asset_id: badge.initial_icon_asset_id,
+                    file_hash: Some(inputs.file_hash),
                 }))
             }
             RobloxInputs::ImageAsset(inputs) => {
@@ -770,6 +2005,63 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
 
                 Ok(RobloxOutputs::Notification(NotificationOutputs { id }))
             }
+            RobloxInputs::ExperienceLocalization(inputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                self.roblox_api
+                    .set_experience_localized_metadata(
+                        experience.asset_id,
+                        &ExperienceLocalizedMetadata {
+                            language_code: inputs.locale.clone(),
+                            name: inputs.title,
+                            description: inputs.description,
+                        },
+                    )
+                    .await?;
+
+                Ok(RobloxOutputs::ExperienceLocalization(
+                    ExperienceLocalizationOutputs {
+                        locale: inputs.locale,
+                    },
+                ))
+            }
+            RobloxInputs::ExperienceCollaborators(inputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                self.sync_experience_collaborators(experience.asset_id, &inputs.permissions)
+                    .await?;
+
+                Ok(RobloxOutputs::ExperienceCollaborators(
+                    ExperienceCollaboratorsOutputs {
+                        permissions: inputs.permissions,
+                    },
+                ))
+            }
+            RobloxInputs::ExperienceAvatar(inputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                validate_universe_avatar(experience.asset_id, &inputs)?;
+
+                self.roblox_api
+                    .configure_universe_avatar(experience.asset_id, &inputs)
+                    .await?;
+
+                Ok(RobloxOutputs::ExperienceAvatar)
+            }
+            RobloxInputs::ExperiencePrivateServers(inputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+                let configuration =
+                    optional_output!(dependency_outputs, RobloxOutputs::ExperienceConfiguration)
+                        .map(Box::as_ref);
+
+                validate_private_server_settings(experience.asset_id, configuration, &inputs)?;
+
+                self.roblox_api
+                    .configure_private_servers(experience.asset_id, &inputs)
+                    .await?;
+
+                Ok(RobloxOutputs::ExperiencePrivateServers)
+            }
         }
     }
 
@@ -790,12 +2082,44 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
         dependency_outputs: Vec<RobloxOutputs>,
         price: Option<u32>,
     ) -> Result<RobloxOutputs, String> {
+        // Places are only changed by their updates when they are created again or become the start
+        // place, so their listing is kept until then
+        if changes_listed_places(&inputs) && !matches!(inputs, RobloxInputs::Place(_)) {
+            self.invalidate_listed_places().await;
+        }
+
         match (inputs.clone(), outputs.clone()) {
-            (RobloxInputs::Experience(_), RobloxOutputs::Experience(_)) => {
+            (
+                RobloxInputs::Experience(experience_inputs),
+                RobloxOutputs::Experience(experience),
+            ) => {
+                validate_experience_id_change(&experience, &experience_inputs)?;
+                // The experience was already deployed to, so there is nothing to change
+                if experience_inputs.asset_id.is_some() {
+                    return Ok(outputs);
+                }
+
                 self.delete(outputs, dependency_outputs.clone()).await?;
                 self.create(inputs, dependency_outputs, price).await
             }
-            (RobloxInputs::ExperienceConfiguration(_), RobloxOutputs::ExperienceConfiguration) => {
+            (
+                RobloxInputs::ExperienceConfiguration(model),
+                RobloxOutputs::ExperienceConfiguration(outputs),
+            ) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                if let (Some(experience_id), Some(applied_configuration)) =
+                    (outputs.experience_id, &outputs.applied_configuration)
+                {
+                    if experience_id == experience.asset_id {
+                        let changed_fields = get_changed_fields(applied_configuration, &model);
+                        if changed_fields.is_empty() {
+                            return Ok(RobloxOutputs::ExperienceConfiguration(outputs));
+                        }
+                        logger::log(format!("Changed fields: {}", changed_fields.join(", ")));
+                    }
+                }
+
                 self.create(inputs, dependency_outputs, price).await
             }
             (RobloxInputs::ExperienceActivation(_), RobloxOutputs::ExperienceActivation) => {
@@ -808,17 +2132,77 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                 self.delete(outputs, dependency_outputs.clone()).await?;
                 self.create(inputs, dependency_outputs, price).await
             }
-            (RobloxInputs::ExperienceThumbnailOrder, RobloxOutputs::ExperienceThumbnailOrder) => {
+            (
+                RobloxInputs::ExperienceThumbnailOrder,
+                RobloxOutputs::ExperienceThumbnailOrder(outputs),
+            ) => {
+                let asset_ids =
+                    all_outputs!(dependency_outputs, RobloxOutputs::ExperienceThumbnail)
+                        .iter()
+                        .map(|t| t.asset_id)
+                        .collect::<Vec<_>>();
+                if asset_ids == outputs.asset_ids {
+                    return Ok(RobloxOutputs::ExperienceThumbnailOrder(outputs));
+                }
+
                 self.create(inputs, dependency_outputs, price).await
             }
-            // TODO: is this correct?
-            (RobloxInputs::Place(_), RobloxOutputs::Place(_)) => {
+            (RobloxInputs::Place(place_inputs), RobloxOutputs::Place(outputs)) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                let places = self.get_listed_places(experience.asset_id).await?;
+                match places.get(&outputs.asset_id) {
+                    // The place still belongs to the experience, so only which place is the start
+                    // place may need to change. Start places are only ever replaced (never
+                    // removed), so the experience cannot be left without a start place.
+                    Some(place) => {
+                        if place_inputs.is_start && !place.is_root_place {
+                            self.invalidate_listed_places().await;
+                            self.roblox_api
+                                .set_start_place(experience.asset_id, outputs.asset_id)
+                                .await?;
+                        }
+                        Ok(RobloxOutputs::Place(outputs))
+                    }
+                    // The experience was replaced, so the place must be created in the new one.
+                    None => self.create(inputs, dependency_outputs, price).await,
+                }
+            }
+            (RobloxInputs::PlaceFile(file_inputs), RobloxOutputs::PlaceFile(outputs)) => {
+                let place = single_output!(dependency_outputs, RobloxOutputs::Place);
+
+                // Recompute the hash rather than trusting the inputs, which may be stale if the
+                // file was changed after the resource graph was created.
+                if let Some(file_path) = file_inputs.file_path {
+                    let file_hash = compute_file_hash(&self.get_path(file_path))?;
+                    if is_place_file_uploaded(&outputs, place.asset_id, &file_hash) {
+                        return Ok(RobloxOutputs::PlaceFile(outputs));
+                    }
+                }
+
                 self.create(inputs, dependency_outputs, price).await
             }
-            (RobloxInputs::PlaceFile(_), RobloxOutputs::PlaceFile(_)) => {
+            (RobloxInputs::PlaceFileBytes(_), RobloxOutputs::PlaceFile(_)) => {
                 self.create(inputs, dependency_outputs, price).await
             }
-            (RobloxInputs::PlaceConfiguration(_), RobloxOutputs::PlaceConfiguration) => {
+            (
+                RobloxInputs::PlaceConfiguration(model),
+                RobloxOutputs::PlaceConfiguration(outputs),
+            ) => {
+                let place = single_output!(dependency_outputs, RobloxOutputs::Place);
+
+                if let (Some(place_id), Some(applied_configuration)) =
+                    (outputs.place_id, &outputs.applied_configuration)
+                {
+                    if place_id == place.asset_id {
+                        let changed_fields = get_changed_fields(applied_configuration, &model);
+                        if changed_fields.is_empty() {
+                            return Ok(RobloxOutputs::PlaceConfiguration(outputs));
+                        }
+                        logger::log(format!("Changed fields: {}", changed_fields.join(", ")));
+                    }
+                }
+
                 self.create(inputs, dependency_outputs, price).await
             }
             (RobloxInputs::SocialLink(inputs), RobloxOutputs::SocialLink(outputs)) => {
@@ -849,6 +2233,7 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                         inputs.name,
                         inputs.price,
                         inputs.description,
+                        inputs.regional_prices.as_ref(),
                     )
                     .await?;
 
@@ -874,6 +2259,26 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     icon_asset_id: icon_image_asset_id,
                 }))
             }
+            (RobloxInputs::Subscription(inputs), RobloxOutputs::Subscription(outputs)) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                let GetDeveloperSubscriptionResponse { image_asset_id, .. } = self
+                    .roblox_api
+                    .update_developer_subscription(
+                        experience.asset_id,
+                        outputs.asset_id,
+                        inputs.name,
+                        inputs.description,
+                        inputs.price,
+                        Some(self.get_path(inputs.icon_file_path)),
+                    )
+                    .await?;
+
+                Ok(RobloxOutputs::Subscription(SubscriptionOutputs {
+                    asset_id: outputs.asset_id,
+                    icon_asset_id: image_asset_id,
+                }))
+            }
             (RobloxInputs::Badge(inputs), RobloxOutputs::Badge(outputs)) => {
                 self.roblox_api
                     .update_badge(
@@ -886,16 +2291,37 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
 
                 Ok(RobloxOutputs::Badge(outputs))
             }
-            (RobloxInputs::BadgeIcon(inputs), RobloxOutputs::BadgeIcon(_)) => {
+            (RobloxInputs::BadgeIcon(inputs), RobloxOutputs::BadgeIcon(outputs)) => {
                 let badge = single_output!(dependency_outputs, RobloxOutputs::Badge);
 
+                // Reuse the current icon if the file has not changed, unless the icon was removed
+                // from the badge outside of Mantle
+                if outputs.file_hash.as_ref() == Some(&inputs.file_hash) {
+                    let GetBadgeResponse { icon_image_id, .. } =
+                        self.roblox_api.get_badge(badge.asset_id).await?;
+                    if icon_image_id == outputs.asset_id {
+                        return Ok(RobloxOutputs::BadgeIcon(outputs));
+                    }
+                    logger::log(format!(
+                        "Badge icon {} is no longer used by badge {}; re-uploading",
+                        outputs.asset_id, badge.asset_id
+                    ));
+                }
+
+                let _permit = self
+                    .image_upload_semaphore
+                    .acquire()
+                    .await
+                    .map_err(|e| format!("Failed to acquire image upload permit: {}", e))?;
+
                 let UploadImageResponse { target_id } = self
                     .roblox_api
                     .update_badge_icon(badge.asset_id, self.get_path(inputs.file_path))
                     .await?;
 
-                Ok(RobloxOutputs::BadgeIcon(AssetOutputs {
+                Ok(RobloxOutputs::BadgeIcon(BadgeIconOutputs {
                     asset_id: target_id,
+                    file_hash: Some(inputs.file_hash),
                 }))
             }
             (RobloxInputs::ImageAsset(_), RobloxOutputs::ImageAsset(_)) => {
@@ -963,6 +2389,37 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
 
                 Ok(RobloxOutputs::Notification(outputs))
             }
+            (
+                RobloxInputs::ExperienceLocalization(inputs),
+                RobloxOutputs::ExperienceLocalization(outputs),
+            ) => {
+                // Remove the previous locale's entry if the locale has changed so that it is not
+                // left behind
+                if inputs.locale != outputs.locale {
+                    self.delete(
+                        RobloxOutputs::ExperienceLocalization(outputs),
+                        dependency_outputs.clone(),
+                    )
+                    .await?;
+                }
+                self.create(
+                    RobloxInputs::ExperienceLocalization(inputs),
+                    dependency_outputs,
+                    price,
+                )
+                .await
+            }
+            (
+                RobloxInputs::ExperienceCollaborators(_),
+                RobloxOutputs::ExperienceCollaborators(_),
+            ) => self.create(inputs, dependency_outputs, price).await,
+            (RobloxInputs::ExperienceAvatar(_), RobloxOutputs::ExperienceAvatar) => {
+                self.create(inputs, dependency_outputs, price).await
+            }
+            (
+                RobloxInputs::ExperiencePrivateServers(_),
+                RobloxOutputs::ExperiencePrivateServers,
+            ) => self.create(inputs, dependency_outputs, price).await,
             _ => unreachable!(),
         }
     }
@@ -973,24 +2430,56 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
         outputs: RobloxOutputs,
         dependency_outputs: Vec<RobloxOutputs>,
     ) -> Result<(), String> {
+        if matches!(
+            outputs,
+            RobloxOutputs::Place(_)
+                | RobloxOutputs::PlaceFile(_)
+                | RobloxOutputs::PlaceConfiguration(_)
+        ) {
+            self.invalidate_listed_places().await;
+        }
+
         match outputs {
             RobloxOutputs::Experience(outputs) => {
-                let model = ExperienceConfigurationModel {
-                    is_archived: true,
-                    ..Default::default()
-                };
-                self.roblox_api
-                    .configure_experience(outputs.asset_id, &model)
-                    .await?;
-            }
-            RobloxOutputs::ExperienceConfiguration => {
-                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+                if !self.allow_experience_destroy {
+                    return Err(format!(
+                        "Refusing to archive experience {} with start place {} because destroying experiences has not been allowed",
+                        outputs.asset_id, outputs.start_place_id
+                    ));
+                }
 
-                let model = ExperienceConfigurationModel::default();
-                self.roblox_api
-                    .configure_experience(experience.asset_id, &model)
-                    .await?;
+                let resource = format!("experience {}", outputs.asset_id);
+                let GetExperienceResponse { root_place_id, .. } = self
+                    .roblox_api
+                    .get_experience(outputs.asset_id)
+                    .await
+                    .map_err(|e| stale_state_error(resource.clone(), e.to_string()))?;
+                // The start place may have been changed to another place in the experience, in
+                // which case the original start place is still part of the experience.
+                if root_place_id != outputs.start_place_id
+                    && !self
+                        .roblox_api
+                        .list_places(outputs.asset_id)
+                        .await?
+                        .iter()
+                        .any(|place| place.id == outputs.start_place_id)
+                {
+                    return Err(stale_state_error(
+                        resource,
+                        format!(
+                            "its start place is {} but the state file expected {}",
+                            root_place_id, outputs.start_place_id
+                        ),
+                    ));
+                }
+
+                // Only archive the experience so that its configuration is left as-is in case it
+                // is restored later.
+                self.roblox_api.archive_experience(outputs.asset_id).await?;
             }
+            // Only the configuration which was specified is ever applied, so removing it leaves the
+            // experience's current configuration as-is rather than resetting it.
+            RobloxOutputs::ExperienceConfiguration(_) => {}
             RobloxOutputs::ExperienceActivation => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
@@ -1012,18 +2501,22 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     .delete_experience_thumbnail(experience.asset_id, outputs.asset_id)
                     .await?;
             }
-            RobloxOutputs::ExperienceThumbnailOrder => {}
+            RobloxOutputs::ExperienceThumbnailOrder(_) => {}
             RobloxOutputs::Place(outputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
-                if outputs.asset_id != experience.start_place_id {
+                // The start place cannot be removed from the experience. Check the live start place
+                // since it may have been changed since the experience was created.
+                let GetExperienceResponse { root_place_id, .. } =
+                    self.roblox_api.get_experience(experience.asset_id).await?;
+                if outputs.asset_id != root_place_id {
                     self.roblox_api
                         .remove_place_from_experience(experience.asset_id, outputs.asset_id)
                         .await?;
                 }
             }
             RobloxOutputs::PlaceFile(_) => {}
-            RobloxOutputs::PlaceConfiguration => {
+            RobloxOutputs::PlaceConfiguration(_) => {
                 let place = single_output!(dependency_outputs, RobloxOutputs::Place);
 
                 let model = PlaceConfigurationModel::default();
@@ -1042,6 +2535,37 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
             RobloxOutputs::Product(outputs) => {
                 let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
 
+                self.roblox_api
+                    .find_experience_developer_product_by_id(experience.asset_id, outputs.asset_id)
+                    .await
+                    .map_err(|e| {
+                        stale_state_error(
+                            format!("developer product {}", outputs.asset_id),
+                            e.to_string(),
+                        )
+                    })?;
+
+                if self.product_delete_behavior == ProductDeleteBehavior::Deactivate {
+                    match self
+                        .roblox_api
+                        .set_developer_product_active(experience.asset_id, outputs.asset_id, false)
+                        .await
+                    {
+                        Ok(()) => return Ok(()),
+                        // Roblox rejects the request if products cannot be made inactive, in which
+                        // case the product is renamed instead.
+                        Err(RobloxApiError::Roblox { status_code, .. })
+                            if status_code.as_u16() == 400 =>
+                        {
+                            info!(
+                                "Unable to deactivate developer product {}, renaming it instead",
+                                outputs.asset_id
+                            );
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+
                 let utc = Utc::now();
                 self.roblox_api
                     .update_developer_product(
@@ -1050,10 +2574,18 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                         format!("zzz_DEPRECATED({})", utc.format("%F %T%.f")),
                         0,
                         "".to_owned(),
+                        None,
                     )
                     .await?;
             }
             RobloxOutputs::Pass(outputs) => {
+                self.roblox_api
+                    .get_game_pass(outputs.asset_id)
+                    .await
+                    .map_err(|e| {
+                        stale_state_error(format!("game pass {}", outputs.asset_id), e.to_string())
+                    })?;
+
                 let utc = Utc::now();
                 self.roblox_api
                     .update_game_pass(
@@ -1066,6 +2598,28 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     .await?;
             }
             RobloxOutputs::Badge(outputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                let resource = format!("badge {}", outputs.asset_id);
+                let GetBadgeResponse {
+                    awarding_universe, ..
+                } = self
+                    .roblox_api
+                    .get_badge(outputs.asset_id)
+                    .await
+                    .map_err(|e| stale_state_error(resource.clone(), e.to_string()))?;
+                if let Some(awarding_universe) = awarding_universe {
+                    if awarding_universe.id != experience.asset_id {
+                        return Err(stale_state_error(
+                            resource,
+                            format!(
+                                "it belongs to experience {} but the state file expected {}",
+                                awarding_universe.id, experience.asset_id
+                            ),
+                        ));
+                    }
+                }
+
                 let utc = Utc::now();
                 self.roblox_api
                     .update_badge(
@@ -1076,6 +2630,14 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
                     )
                     .await?;
             }
+            RobloxOutputs::Subscription(outputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                // Subscriptions cannot be deleted, so deactivate them instead
+                self.roblox_api
+                    .deactivate_developer_subscription(experience.asset_id, outputs.asset_id)
+                    .await?;
+            }
             RobloxOutputs::BadgeIcon(_) => {}
             RobloxOutputs::ImageAsset(outputs) => {
                 // TODO: Can we make this not optional and just not import the image asset? Maybe?
@@ -1106,9 +2668,156 @@ impl ResourceManager<RobloxInputs, RobloxOutputs> for RobloxResourceManager {
             RobloxOutputs::Notification(outputs) => {
                 self.roblox_api.archive_notification(outputs.id).await?;
             }
+            RobloxOutputs::ExperienceLocalization(outputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                self.roblox_api
+                    .delete_experience_localized_metadata(experience.asset_id, &outputs.locale)
+                    .await?;
+            }
+            RobloxOutputs::ExperienceCollaborators(outputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                // Only remove the permissions which Mantle granted
+                let is_owner = self.get_experience_owner_check(experience.asset_id).await?;
+                let permissions = outputs
+                    .permissions
+                    .into_iter()
+                    .filter(|permission| !is_owner(permission))
+                    .collect::<Vec<_>>();
+                if !permissions.is_empty() {
+                    self.roblox_api
+                        .remove_experience_permissions(experience.asset_id, &permissions)
+                        .await?;
+                }
+            }
+            RobloxOutputs::ExperienceAvatar => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                // Body colors cannot be cleared, so only the animation overrides are reset.
+                let model = UniverseAvatarModel {
+                    universe_avatar_asset_overrides: [
+                        AssetTypeId::ClimbAnimation,
+                        AssetTypeId::FallAnimation,
+                        AssetTypeId::IdleAnimation,
+                        AssetTypeId::JumpAnimation,
+                        AssetTypeId::RunAnimation,
+                        AssetTypeId::SwimAnimation,
+                        AssetTypeId::WalkAnimation,
+                    ]
+                    .into_iter()
+                    .map(ExperienceAvatarAssetOverride::player_choice)
+                    .collect(),
+                    ..Default::default()
+                };
+                self.roblox_api
+                    .configure_universe_avatar(experience.asset_id, &model)
+                    .await?;
+            }
+            RobloxOutputs::ExperiencePrivateServers => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                // Lift the subscriber-only restriction, but leave link sharing unchanged since its
+                // previous value is not known.
+                let settings = PrivateServerSettingsModel {
+                    is_subscribers_only: Some(false),
+                    ..Default::default()
+                };
+                self.roblox_api
+                    .configure_private_servers(experience.asset_id, &settings)
+                    .await?;
+            }
         }
         Ok(())
     }
+
+    async fn exists(
+        &self,
+        outputs: RobloxOutputs,
+        dependency_outputs: Vec<RobloxOutputs>,
+    ) -> Result<bool, String> {
+        let result = match outputs {
+            RobloxOutputs::Experience(outputs) => self
+                .roblox_api
+                .get_experience(outputs.asset_id)
+                .await
+                .map(|_| ()),
+            RobloxOutputs::Place(outputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                self.get_listed_place(Some(experience.asset_id), outputs.asset_id)
+                    .await
+                    .map(|_| ())
+            }
+            RobloxOutputs::Product(outputs) => {
+                let experience = single_output!(dependency_outputs, RobloxOutputs::Experience);
+
+                self.roblox_api
+                    .find_experience_developer_product_by_id(experience.asset_id, outputs.asset_id)
+                    .await
+                    .map(|_| ())
+            }
+            RobloxOutputs::Pass(outputs) => self
+                .roblox_api
+                .get_game_pass(outputs.asset_id)
+                .await
+                .map(|_| ()),
+            RobloxOutputs::Badge(outputs) => self
+                .roblox_api
+                .get_badge(outputs.asset_id)
+                .await
+                .map(|_| ()),
+            // Other resources do not have an endpoint to look them up
+            _ => Ok(()),
+        };
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(RobloxApiError::Roblox { status_code, .. }) if status_code.as_u16() == 404 => {
+                Ok(false)
+            }
+            Err(RobloxApiError::DeveloperProductNotFound { .. }) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn get_drifted_fields(
+        &self,
+        outputs: RobloxOutputs,
+        _dependency_outputs: Vec<RobloxOutputs>,
+    ) -> Result<Vec<String>, String> {
+        match outputs {
+            RobloxOutputs::ExperienceConfiguration(outputs) => {
+                if let (Some(experience_id), Some(applied_configuration)) =
+                    (outputs.experience_id, &outputs.applied_configuration)
+                {
+                    let live_configuration = self
+                        .roblox_api
+                        .get_experience_configuration(experience_id)
+                        .await?;
+
+                    Ok(get_drifted_fields(
+                        applied_configuration,
+                        &live_configuration,
+                    ))
+                } else {
+                    Ok(Vec::new())
+                }
+            }
+            RobloxOutputs::PlaceConfiguration(PlaceConfigurationOutputs {
+                place_id: Some(place_id),
+                applied_configuration: Some(applied_configuration),
+            }) => {
+                let live_place = self.get_listed_place(None, place_id).await?;
+
+                Ok(get_drifted_fields(
+                    &applied_configuration,
+                    &PlaceConfigurationModel::from(live_place),
+                ))
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
 }
 
 fn format_quota_reset(reset: DateTime<Utc>) -> String {
@@ -1145,3 +2854,715 @@ fn format_quota_reset(reset: DateTime<Utc>) -> String {
 
     parts.join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, sync::Arc};
+
+    use rbx_api::{http_client::HttpClient, retry::RetryPolicy};
+    use reqwest::Method;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TestModel {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    }
+
+    #[test]
+    fn get_changed_fields_ignores_unchanged_fields() {
+        let previous = TestModel {
+            name: "name".to_owned(),
+            description: Some("description".to_owned()),
+        };
+        let next = TestModel {
+            name: "name".to_owned(),
+            description: Some("description".to_owned()),
+        };
+
+        assert!(get_changed_fields(&previous, &next).is_empty());
+    }
+
+    #[test]
+    fn get_changed_fields_includes_changed_fields() {
+        let previous = TestModel {
+            name: "name".to_owned(),
+            description: None,
+        };
+        let next = TestModel {
+            name: "new name".to_owned(),
+            description: None,
+        };
+
+        assert_eq!(get_changed_fields(&previous, &next), vec!["name"]);
+    }
+
+    #[test]
+    fn get_changed_fields_includes_removed_fields() {
+        let previous = TestModel {
+            name: "name".to_owned(),
+            description: Some("description".to_owned()),
+        };
+        let next = TestModel {
+            name: "name".to_owned(),
+            description: None,
+        };
+
+        assert_eq!(get_changed_fields(&previous, &next), vec!["description"]);
+    }
+
+    #[test]
+    fn one_byte_change_to_place_file_is_uploaded_again() {
+        let file_path = env::temp_dir().join(format!(
+            "mantle-test-place-file-{}.rbxl",
+            std::process::id()
+        ));
+        fs::write(&file_path, b"place file contents").unwrap();
+        let outputs = PlaceFileOutputs {
+            version: 1,
+            place_id: Some(123),
+            file_hash: Some(compute_file_hash(&file_path).unwrap()),
+        };
+        assert!(is_place_file_uploaded(
+            &outputs,
+            123,
+            &compute_file_hash(&file_path).unwrap()
+        ));
+
+        // Change a single byte while keeping the same path
+        fs::write(&file_path, b"place file contentz").unwrap();
+        let file_hash = compute_file_hash(&file_path).unwrap();
+        fs::remove_file(&file_path).unwrap();
+
+        assert_ne!(outputs.file_hash.as_deref(), Some(file_hash.as_str()));
+        assert!(!is_place_file_uploaded(&outputs, 123, &file_hash));
+    }
+
+    #[test]
+    fn place_file_is_uploaded_again_to_a_different_place() {
+        let file_hash = compute_hash(b"place file contents");
+        let outputs = PlaceFileOutputs {
+            version: 1,
+            place_id: Some(123),
+            file_hash: Some(file_hash.clone()),
+        };
+
+        assert!(!is_place_file_uploaded(&outputs, 456, &file_hash));
+    }
+
+    #[test]
+    fn get_start_place_error_explains_missing_start_places() {
+        let error = RobloxApiError::Roblox {
+            status_code: StatusCode::NOT_FOUND,
+            reason: "The place does not exist.".to_owned(),
+        };
+
+        assert_eq!(
+            get_start_place_error(123, error),
+            "start place 123 not found; the experience may have been deleted — remove it from state and re-create"
+        );
+    }
+
+    #[test]
+    fn get_start_place_error_reports_other_errors() {
+        let error = RobloxApiError::Roblox {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            reason: "Internal server error".to_owned(),
+        };
+
+        assert_eq!(
+            get_start_place_error(123, error),
+            "Roblox error (500 Internal Server Error): Internal server error"
+        );
+    }
+
+    #[test]
+    fn validate_paid_access_accepts_prices_in_range() {
+        let mut model = ExperienceConfigurationModel {
+            is_for_sale: Some(true),
+            price: Some(MIN_PAID_ACCESS_PRICE),
+            ..Default::default()
+        };
+
+        assert!(validate_paid_access(1, &mut model).is_ok());
+        assert_eq!(model.price, Some(MIN_PAID_ACCESS_PRICE));
+    }
+
+    #[test]
+    fn validate_paid_access_rejects_missing_prices() {
+        let mut model = ExperienceConfigurationModel {
+            is_for_sale: Some(true),
+            price: None,
+            ..Default::default()
+        };
+
+        assert!(validate_paid_access(1, &mut model).is_err());
+    }
+
+    #[test]
+    fn validate_paid_access_rejects_prices_out_of_range() {
+        for price in [MIN_PAID_ACCESS_PRICE - 1, MAX_PAID_ACCESS_PRICE + 1] {
+            let mut model = ExperienceConfigurationModel {
+                is_for_sale: Some(true),
+                price: Some(price),
+                ..Default::default()
+            };
+
+            assert!(validate_paid_access(1, &mut model).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_paid_access_clears_price_when_free() {
+        let mut model = ExperienceConfigurationModel {
+            is_for_sale: Some(false),
+            price: Some(100),
+            ..Default::default()
+        };
+
+        assert!(validate_paid_access(1, &mut model).is_ok());
+        assert_eq!(model.price, None);
+    }
+
+    #[test]
+    fn validate_private_servers_accepts_free_and_paid_private_servers() {
+        for price in [0, 100] {
+            let mut model = ExperienceConfigurationModel {
+                allow_private_servers: Some(true),
+                private_server_price: Some(price),
+                ..Default::default()
+            };
+
+            assert!(validate_private_servers(1, &mut model).is_ok());
+            assert_eq!(model.private_server_price, Some(price));
+        }
+    }
+
+    #[test]
+    fn validate_private_servers_rejects_missing_prices() {
+        let mut model = ExperienceConfigurationModel {
+            allow_private_servers: Some(true),
+            private_server_price: None,
+            ..Default::default()
+        };
+
+        assert!(validate_private_servers(1, &mut model).is_err());
+    }
+
+    #[test]
+    fn validate_private_servers_rejects_prices_when_disabled() {
+        let mut model = ExperienceConfigurationModel {
+            allow_private_servers: Some(false),
+            private_server_price: Some(100),
+            ..Default::default()
+        };
+
+        assert!(validate_private_servers(1, &mut model).is_err());
+    }
+
+    #[test]
+    fn validate_private_servers_clears_free_price_when_disabled() {
+        let mut model = ExperienceConfigurationModel {
+            allow_private_servers: Some(false),
+            private_server_price: Some(0),
+            ..Default::default()
+        };
+
+        assert!(validate_private_servers(1, &mut model).is_ok());
+        assert_eq!(model.private_server_price, None);
+    }
+
+    #[test]
+    fn purchase_urls_use_the_public_asset_ids() {
+        let product = ProductOutputs {
+            asset_id: 1,
+            product_id: 2,
+        };
+        let pass = PassOutputs {
+            asset_id: 3,
+            icon_asset_id: 4,
+        };
+
+        assert_eq!(
+            product.purchase_url(),
+            "https://www.roblox.com/developer-products/1"
+        );
+        assert_eq!(pass.shop_url(), "https://www.roblox.com/game-pass/3");
+    }
+
+    #[test]
+    fn is_authorization_error_matches_rejected_cookies() {
+        assert!(is_authorization_error(
+            &RobloxApiError::Authorization.to_string()
+        ));
+        assert!(is_authorization_error(
+            &RobloxApiError::Roblox {
+                status_code: StatusCode::UNAUTHORIZED,
+                reason: "Unauthorized".to_owned(),
+            }
+            .to_string()
+        ));
+        assert!(is_authorization_error(
+            &RobloxApiError::Roblox {
+                status_code: StatusCode::FORBIDDEN,
+                reason: "Token Validation Failed".to_owned(),
+            }
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn is_authorization_error_ignores_other_errors() {
+        assert!(!is_authorization_error(
+            &RobloxApiError::Roblox {
+                status_code: StatusCode::BAD_REQUEST,
+                reason: "Invalid request".to_owned(),
+            }
+            .to_string()
+        ));
+        assert!(!is_authorization_error(
+            "Failed to read file: No such file or directory"
+        ));
+    }
+
+    fn get_configuration_outputs(allow_private_servers: bool) -> ExperienceConfigurationOutputs {
+        ExperienceConfigurationOutputs {
+            experience_id: Some(1),
+            applied_configuration: Some(ExperienceConfigurationModel {
+                allow_private_servers: Some(allow_private_servers),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn validate_private_server_settings_accepts_settings_with_private_servers() {
+        let configuration = get_configuration_outputs(true);
+        let settings = PrivateServerSettingsModel {
+            is_link_sharing_enabled: Some(true),
+            is_subscribers_only: Some(true),
+        };
+
+        assert!(validate_private_server_settings(1, Some(&configuration), &settings).is_ok());
+    }
+
+    #[test]
+    fn validate_private_server_settings_rejects_settings_without_private_servers() {
+        let configuration = get_configuration_outputs(false);
+        for settings in [
+            PrivateServerSettingsModel {
+                is_link_sharing_enabled: Some(true),
+                ..Default::default()
+            },
+            PrivateServerSettingsModel {
+                is_subscribers_only: Some(true),
+                ..Default::default()
+            },
+        ] {
+            assert!(validate_private_server_settings(1, Some(&configuration), &settings).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_private_server_settings_accepts_disabled_settings_without_private_servers() {
+        let configuration = get_configuration_outputs(false);
+        let settings = PrivateServerSettingsModel {
+            is_link_sharing_enabled: Some(false),
+            is_subscribers_only: Some(false),
+        };
+
+        assert!(validate_private_server_settings(1, Some(&configuration), &settings).is_ok());
+    }
+
+    #[test]
+    fn validate_private_server_settings_accepts_unknown_configuration() {
+        let settings = PrivateServerSettingsModel {
+            is_link_sharing_enabled: Some(true),
+            is_subscribers_only: Some(true),
+        };
+
+        assert!(validate_private_server_settings(1, None, &settings).is_ok());
+    }
+
+    #[test]
+    fn place_configuration_length_problems_accepts_maximum_lengths() {
+        let name = "a".repeat(MAX_PLACE_NAME_LENGTH);
+        let description = "a".repeat(MAX_PLACE_DESCRIPTION_LENGTH);
+
+        assert!(get_place_configuration_length_problems("start", &name, &description).is_empty());
+    }
+
+    #[test]
+    fn place_configuration_length_problems_reports_each_long_field() {
+        let name = "a".repeat(MAX_PLACE_NAME_LENGTH + 1);
+        let description = "a".repeat(MAX_PLACE_DESCRIPTION_LENGTH + 1);
+
+        assert_eq!(
+            get_place_configuration_length_problems("start", &name, &description),
+            vec![
+                "The name of place start is 51 characters long, but it can be at most 50 characters."
+                    .to_owned(),
+                "The description of place start is 1001 characters long, but it can be at most 1000 characters."
+                    .to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn place_configuration_length_problems_counts_characters() {
+        // Each of these characters is several bytes long
+        let name = "é".repeat(MAX_PLACE_NAME_LENGTH);
+
+        assert!(get_place_configuration_length_problems("start", &name, "").is_empty());
+    }
+
+    fn get_deployed_experience() -> ExperienceOutputs {
+        ExperienceOutputs {
+            asset_id: 123,
+            start_place_id: 1,
+            is_archived: false,
+        }
+    }
+
+    #[test]
+    fn validate_experience_id_change_refuses_to_switch_experiences() {
+        let inputs = ExperienceInputs {
+            group_id: None,
+            asset_id: Some(456),
+        };
+
+        assert_eq!(
+            validate_experience_id_change(&get_deployed_experience(), &inputs),
+            Err("The experience ID was changed from 123 to 456. Switching the underlying experience is not supported: run `mantle destroy` to destroy the environment's resources in experience 123, then deploy again to use experience 456.".to_owned())
+        );
+    }
+
+    #[test]
+    fn validate_experience_id_change_allows_the_deployed_experience() {
+        let inputs = ExperienceInputs {
+            group_id: Some(789),
+            asset_id: Some(123),
+        };
+
+        assert!(validate_experience_id_change(&get_deployed_experience(), &inputs).is_ok());
+    }
+
+    #[test]
+    fn validate_experience_id_change_allows_experiences_without_an_id() {
+        let inputs = ExperienceInputs {
+            group_id: Some(789),
+            asset_id: None,
+        };
+
+        assert!(validate_experience_id_change(&get_deployed_experience(), &inputs).is_ok());
+    }
+
+    #[test]
+    fn get_configure_experience_error_explains_paid_access_ineligibility() {
+        let model = ExperienceConfigurationModel {
+            is_for_sale: Some(true),
+            price: Some(100),
+            ..Default::default()
+        };
+        let error = RobloxApiError::Roblox {
+            status_code: StatusCode::FORBIDDEN,
+            reason: "User is not eligible to sell experiences".to_owned(),
+        };
+
+        assert_eq!(
+            get_configure_experience_error(123, &model, error),
+            "Experience 123 has paid access enabled, but its creator is not eligible to sell access to experiences: User is not eligible to sell experiences. Disable paid access or check the creator's eligibility on the Creator Dashboard."
+        );
+    }
+
+    #[test]
+    fn get_configure_experience_error_reports_forbidden_errors_without_paid_access() {
+        let model = ExperienceConfigurationModel {
+            is_for_sale: Some(false),
+            ..Default::default()
+        };
+        let error = RobloxApiError::Roblox {
+            status_code: StatusCode::FORBIDDEN,
+            reason: "Forbidden".to_owned(),
+        };
+
+        assert_eq!(
+            get_configure_experience_error(123, &model, error),
+            "Roblox error (403 Forbidden): Forbidden"
+        );
+    }
+
+    /// Responds to each request with the next canned JSON response and records the requests
+    /// which were sent, along with their bodies.
+    #[derive(Clone, Default)]
+    struct MockHttpClient {
+        responses: Arc<Mutex<VecDeque<(StatusCode, &'static str)>>>,
+        requests: Arc<Mutex<Vec<(Method, String, String)>>>,
+    }
+
+    impl MockHttpClient {
+        fn new(responses: Vec<(StatusCode, &'static str)>) -> Self {
+            Self {
+                responses: Arc::new(Mutex::new(responses.into())),
+                ..Default::default()
+            }
+        }
+
+        fn requests(&self) -> Vec<(Method, String, String)> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn manager(&self) -> RobloxResourceManager {
+            let roblox_auth = RobloxAuth {
+                jar: Default::default(),
+                headers: Default::default(),
+            };
+            let roblox_api = RobloxApi::new_with_proxy(roblox_auth, None)
+                .unwrap()
+                .with_http_client(self.clone())
+                .with_retry_policy(RetryPolicy::none());
+            RobloxResourceManager::with_roblox_api(roblox_api, Path::new("."), CreatorType::User)
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for MockHttpClient {
+        async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+            let body = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+                .unwrap_or_default();
+            self.requests.lock().unwrap().push((
+                request.method().clone(),
+                request.url().to_string(),
+                body,
+            ));
+            let (status_code, body) = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| panic!("Unexpected request to {}", request.url()));
+
+            Ok(http::Response::builder()
+                .status(status_code)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body)
+                .unwrap()
+                .into())
+        }
+    }
+
+    const NO_PLACES: &str = r#"{"data":[],"nextPageCursor":null}"#;
+
+    fn get_experience_outputs() -> RobloxOutputs {
+        RobloxOutputs::Experience(ExperienceOutputs {
+            asset_id: 1,
+            start_place_id: 2,
+            is_archived: false,
+        })
+    }
+
+    #[tokio::test]
+    async fn created_places_are_given_their_name() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, NO_PLACES),
+            (StatusCode::OK, r#"{"placeId":3}"#),
+            (StatusCode::OK, "{}"),
+        ]);
+
+        let outputs = http_client
+            .manager()
+            .create(
+                RobloxInputs::Place(PlaceInputs {
+                    is_start: false,
+                    name: Some("Lobby".to_owned()),
+                    template_place_id: None,
+                }),
+                vec![get_experience_outputs()],
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            outputs,
+            RobloxOutputs::Place(AssetOutputs { asset_id: 3 })
+        ));
+        let requests = http_client.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].0, Method::POST);
+        assert_eq!(
+            requests[1].1,
+            "https://apis.roblox.com/universes/v1/user/universes/1/places"
+        );
+        assert_eq!(
+            requests[2],
+            (
+                Method::PATCH,
+                "https://develop.roblox.com/v2/places/3".to_owned(),
+                r#"{"name":"Lobby"}"#.to_owned()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn created_places_without_a_name_are_not_renamed() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, NO_PLACES),
+            (StatusCode::OK, r#"{"placeId":3}"#),
+        ]);
+
+        http_client
+            .manager()
+            .create(
+                RobloxInputs::Place(PlaceInputs {
+                    is_start: false,
+                    name: None,
+                    template_place_id: None,
+                }),
+                vec![get_experience_outputs()],
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(!http_client
+            .requests()
+            .iter()
+            .any(|(method, _, _)| method == Method::PATCH));
+    }
+
+    const LISTED_PLACE_IDS: &str = r#"{"data":[{"id":2},{"id":3}],"nextPageCursor":null}"#;
+    const START_PLACE: &str = r#"{"id":2,"currentSavedVersion":1,"name":"Start","description":"","maxPlayerCount":10,"allowCopying":false,"socialSlotType":"Automatic","customSocialSlotsCount":null,"isRootPlace":true}"#;
+    const OTHER_PLACE: &str = r#"{"id":3,"currentSavedVersion":1,"name":"Lobby","description":"","maxPlayerCount":10,"allowCopying":false,"socialSlotType":"Automatic","customSocialSlotsCount":null,"isRootPlace":false}"#;
+
+    fn get_place_inputs(is_start: bool) -> RobloxInputs {
+        RobloxInputs::Place(PlaceInputs {
+            is_start,
+            name: None,
+            template_place_id: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn updated_places_are_listed_once() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, LISTED_PLACE_IDS),
+            (StatusCode::OK, START_PLACE),
+            (StatusCode::OK, OTHER_PLACE),
+        ]);
+        let manager = http_client.manager();
+
+        for (is_start, asset_id) in [(true, 2), (false, 3)] {
+            manager
+                .update(
+                    get_place_inputs(is_start),
+                    RobloxOutputs::Place(AssetOutputs { asset_id }),
+                    vec![get_experience_outputs()],
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(http_client.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn places_are_listed_again_after_becoming_the_start_place() {
+        let http_client = MockHttpClient::new(vec![
+            (StatusCode::OK, LISTED_PLACE_IDS),
+            (StatusCode::OK, START_PLACE),
+            (StatusCode::OK, OTHER_PLACE),
+            (StatusCode::OK, "{}"),
+            (StatusCode::OK, LISTED_PLACE_IDS),
+            (StatusCode::OK, START_PLACE),
+            (StatusCode::OK, OTHER_PLACE),
+        ]);
+        let manager = http_client.manager();
+
+        for asset_id in [3, 2] {
+            manager
+                .update(
+                    get_place_inputs(asset_id == 3),
+                    RobloxOutputs::Place(AssetOutputs { asset_id }),
+                    vec![get_experience_outputs()],
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        let requests = http_client.requests();
+        assert_eq!(requests.len(), 7);
+        assert_eq!(
+            requests[3],
+            (
+                Method::PATCH,
+                "https://develop.roblox.com/v2/universes/1/configuration".to_owned(),
+                r#"{"rootPlaceId":3}"#.to_owned()
+            )
+        );
+        assert_eq!(
+            requests[4].1,
+            "https://develop.roblox.com/v1/universes/1/places?limit=100"
+        );
+    }
+
+    #[test]
+    fn new_start_places_change_the_experience_start_place() {
+        let manager = MockHttpClient::default().manager();
+
+        let experience = manager.get_changed_dependency_outputs(
+            &get_place_inputs(true),
+            &RobloxOutputs::Place(AssetOutputs { asset_id: 3 }),
+            &get_experience_outputs(),
+        );
+
+        assert!(matches!(
+            experience,
+            Some(RobloxOutputs::Experience(ExperienceOutputs {
+                asset_id: 1,
+                start_place_id: 3,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn other_places_do_not_change_the_experience() {
+        let manager = MockHttpClient::default().manager();
+
+        for (is_start, asset_id) in [(true, 2), (false, 3)] {
+            assert!(manager
+                .get_changed_dependency_outputs(
+                    &get_place_inputs(is_start),
+                    &RobloxOutputs::Place(AssetOutputs { asset_id }),
+                    &get_experience_outputs(),
+                )
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn validate_paid_access_ignores_unspecified_paid_access() {
+        let mut model = ExperienceConfigurationModel::default();
+
+        assert!(validate_paid_access(1, &mut model).is_ok());
+        assert_eq!(model.is_for_sale, None);
+        assert_eq!(model.price, None);
+    }
+
+    #[test]
+    fn validate_private_servers_ignores_unspecified_private_servers() {
+        let mut model = ExperienceConfigurationModel::default();
+
+        assert!(validate_private_servers(1, &mut model).is_ok());
+        assert_eq!(model.allow_private_servers, None);
+        assert_eq!(model.private_server_price, None);
+    }
+}
@@ -0,0 +1,193 @@
+use std::env;
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use serde::{Deserialize, Serialize};
+
+const ENCRYPTION_KEY_VAR: &str = "MANTLE_STATE_ENCRYPTION_KEY";
+const ENCRYPTION_ALGORITHM: &str = "aes-256-gcm";
+
+/// A state file whose serialized contents have been encrypted with a symmetric key sourced from
+/// the `MANTLE_STATE_ENCRYPTION_KEY` environment variable. The key must be a base64-encoded
+/// 256-bit key (e.g. generated with `openssl rand -base64 32`) rather than a passphrase.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EncryptedState {
+    encryption: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+fn parse_key(key: &str) -> Result<Aes256Gcm, String> {
+    let key = base64::decode(key.trim()).map_err(|e| {
+        format!(
+            "The {} environment variable is not a base64-encoded key\n\t{}",
+            ENCRYPTION_KEY_VAR, e
+        )
+    })?;
+    Aes256Gcm::new_from_slice(&key).map_err(|_| {
+        format!(
+            "The {} environment variable must be a base64-encoded 32-byte key, but it is {} bytes long. Generate one with `openssl rand -base64 32`.",
+            ENCRYPTION_KEY_VAR,
+            key.len()
+        )
+    })
+}
+
+impl EncryptedState {
+    /// Returns true if a state encryption key has been provided.
+    pub fn is_enabled() -> bool {
+        env::var(ENCRYPTION_KEY_VAR).is_ok()
+    }
+
+    /// Attempts to parse the data as an encrypted state file. Returns `None` if the data is not
+    /// an encrypted state file so that it can be parsed as a plain state file instead.
+    pub fn parse(data: &str) -> Option<Self> {
+        serde_yaml::from_str::<EncryptedState>(data).ok()
+    }
+
+    pub fn encrypt(data: &[u8]) -> Result<Self, String> {
+        Self::encrypt_with_key(data, env::var(ENCRYPTION_KEY_VAR).ok().as_deref())
+    }
+
+    pub fn decrypt(&self, file_name: &str) -> Result<String, String> {
+        self.decrypt_with_key(file_name, env::var(ENCRYPTION_KEY_VAR).ok().as_deref())
+    }
+
+    fn encrypt_with_key(data: &[u8], key: Option<&str>) -> Result<Self, String> {
+        let cipher = parse_key(key.ok_or_else(|| {
+            format!(
+                "Unable to encrypt state: the {} environment variable is not set",
+                ENCRYPTION_KEY_VAR
+            )
+        })?)?;
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, data)
+            .map_err(|e| format!("Unable to encrypt state\n\t{}", e))?;
+
+        Ok(Self {
+            encryption: ENCRYPTION_ALGORITHM.to_owned(),
+            nonce: base64::encode(nonce),
+            ciphertext: base64::encode(ciphertext),
+        })
+    }
+
+    fn decrypt_with_key(&self, file_name: &str, key: Option<&str>) -> Result<String, String> {
+        if self.encryption != ENCRYPTION_ALGORITHM {
+            return Err(format!(
+                "Unable to decrypt state file {}: unsupported encryption algorithm {}",
+                file_name, self.encryption
+            ));
+        }
+
+        let cipher = parse_key(key.ok_or_else(|| {
+            format!(
+                "State file {} is encrypted but no key was provided. Set the {} environment variable to decrypt it.",
+                file_name, ENCRYPTION_KEY_VAR
+            )
+        })?)?;
+
+        let nonce = base64::decode(&self.nonce).map_err(|e| {
+            format!(
+                "Unable to decode nonce of state file {}\n\t{}",
+                file_name, e
+            )
+        })?;
+        let ciphertext = base64::decode(&self.ciphertext).map_err(|e| {
+            format!(
+                "Unable to decode ciphertext of state file {}\n\t{}",
+                file_name, e
+            )
+        })?;
+        if nonce.len() != 12 {
+            return Err(format!(
+                "Unable to decrypt state file {}: invalid nonce length",
+                file_name
+            ));
+        }
+
+        let data = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|_| {
+                format!(
+                    "Unable to decrypt state file {}. The {} environment variable does not match the key used to encrypt it.",
+                    file_name, ENCRYPTION_KEY_VAR
+                )
+            })?;
+
+        String::from_utf8(data).map_err(|e| {
+            format!(
+                "Decrypted state file {} is not valid UTF-8\n\t{}",
+                file_name, e
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+    const OTHER_KEY: &str = "HxwdGxoZGBcWFRQTEhEQDw4NDAsKCQgHBgUEAwIBAAA=";
+
+    fn encrypt(data: &str) -> EncryptedState {
+        let encrypted = EncryptedState::encrypt_with_key(data.as_bytes(), Some(KEY)).unwrap();
+        EncryptedState::parse(&serde_yaml::to_string(&encrypted).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn decrypts_with_the_same_key() {
+        let encrypted = encrypt("version: 6");
+
+        assert_ne!(encrypted.ciphertext, base64::encode("version: 6"));
+        assert_eq!(
+            encrypted.decrypt_with_key(".mantle-state.yml", Some(KEY)),
+            Ok("version: 6".to_owned())
+        );
+    }
+
+    #[test]
+    fn reports_a_missing_key() {
+        let error = encrypt("version: 6")
+            .decrypt_with_key(".mantle-state.yml", None)
+            .unwrap_err();
+
+        assert!(
+            error.contains("is encrypted but no key was provided"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn reports_a_wrong_key() {
+        let error = encrypt("version: 6")
+            .decrypt_with_key(".mantle-state.yml", Some(OTHER_KEY))
+            .unwrap_err();
+
+        assert!(error.contains("does not match the key"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_keys_which_are_not_256_bits() {
+        let error = EncryptedState::encrypt_with_key(b"version: 6", Some("cGFzc3dvcmQ="))
+            .err()
+            .unwrap();
+
+        assert!(
+            error.contains("must be a base64-encoded 32-byte key"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn does_not_parse_plain_state_files() {
+        assert!(EncryptedState::parse("version: 6\nenvironments: {}\n").is_none());
+    }
+}
@@ -1,4 +1,5 @@
 mod aws_credentials_provider;
+mod encrypted_state;
 mod legacy_resources;
 pub mod v1;
 pub mod v2;
@@ -11,37 +12,46 @@ use std::{
     collections::BTreeMap,
     ffi::OsStr,
     fs,
+    io::Write,
     path::{Path, PathBuf},
+    str,
 };
 
-use chrono::Utc;
 use clap::crate_version;
 use rbx_api::{
-    experiences::models::GetExperienceResponse,
+    developer_subscriptions::models::DeveloperSubscriptionPeriod,
+    experiences::models::{
+        ExperienceCollaboratorAction, ExperienceCollaboratorPermission,
+        ExperienceCollaboratorSubjectType, GetExperienceResponse, PrivateServerSettingsModel,
+    },
     models::{AssetId, CreatorType},
+    places::models::{GetPlaceResponse, PlaceConfigurationModel, PlaceFileFormat},
     social_links::models::SocialLinkType,
     RobloxApi,
 };
 use rusoto_core::{HttpClient, Region};
 use rusoto_s3::{S3Client, S3};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use tokio::io::AsyncReadExt;
 use v6::ResourceStateV6;
 use yansi::Paint;
 
 use super::{
     config::{
-        AssetTargetConfig, Config, EnvironmentConfig, ExperienceTargetConfig, OwnerConfig,
-        PlayabilityTargetConfig, RemoteStateConfig, StateConfig, TargetConfig,
+        AssetTargetConfig, CollaboratorAccessTargetConfig, Config, EnvironmentConfig,
+        ExperienceTargetConfig, ExperienceTargetConfigurationConfig, OwnerConfig,
+        PlayabilityTargetConfig, PrivateServersTargetConfig, RemoteStateConfig, StateConfig,
+        SubscriptionPeriodTargetConfig, TargetConfig, ThumbnailTargetConfig,
     },
-    resource_graph::ResourceGraph,
+    remote_images::{download_remote_images, get_remote_image_path},
+    resource_graph::{Resource, ResourceGraph},
     roblox_resource_manager::*,
 };
 
 use self::{
-    aws_credentials_provider::AwsCredentialsProvider, v1::ResourceStateV1, v2::ResourceStateV2,
-    v3::ResourceStateV3, v4::ResourceStateV4, v5::ResourceStateV5,
+    aws_credentials_provider::AwsCredentialsProvider, encrypted_state::EncryptedState,
+    v1::ResourceStateV1, v2::ResourceStateV2, v3::ResourceStateV3, v4::ResourceStateV4,
+    v5::ResourceStateV5,
 };
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -74,23 +84,31 @@ fn get_state_file_path(project_path: &Path, key: Option<&str>) -> PathBuf {
     project_path.join(format!("{}.mantle-state.yml", key.unwrap_or_default()))
 }
 
-fn get_hash(data: &[u8]) -> String {
-    let digest = Sha256::digest(data);
-    format!("{:x}", digest)
+/// Appends an extension to a state file path (e.g. `.mantle-state.yml.bak`).
+fn with_extra_extension(state_file_path: &Path, extension: &str) -> PathBuf {
+    let mut path = state_file_path.as_os_str().to_owned();
+    path.push(format!(".{}", extension));
+    PathBuf::from(path)
 }
 
-fn get_file_hash(file_path: PathBuf) -> Result<String, String> {
-    let buffer = fs::read(&file_path).map_err(|e| {
+fn read_state_file(state_file_path: &Path) -> Result<ResourceState, String> {
+    let data = fs::read_to_string(state_file_path).map_err(|e| {
         format!(
-            "Failed to read file {} for hashing: {}",
-            file_path.display(),
+            "Unable to read state file: {}\n\t{}",
+            state_file_path.display(),
             e
         )
     })?;
-    Ok(get_hash(&buffer))
+
+    parse_state(&state_file_path.display().to_string(), &data)
 }
 
 fn parse_state(file_name: &str, data: &str) -> Result<ResourceState, String> {
+    if let Some(encrypted_state) = EncryptedState::parse(data) {
+        let data = encrypted_state.decrypt(file_name)?;
+        return parse_state(file_name, &data);
+    }
+
     serde_yaml::from_str::<ResourceState>(data)
         .map_err(|e| format!("Unable to parse state file {}\n\t{}", file_name, e))
 }
@@ -106,18 +124,24 @@ fn get_state_from_file(
     ));
 
     if state_file_path.exists() {
-        let data = fs::read_to_string(&state_file_path).map_err(|e| {
-            format!(
-                "Unable to read state file: {}\n\t{}",
-                state_file_path.display(),
-                e
-            )
-        })?;
-
-        return Ok(Some(parse_state(
-            &state_file_path.display().to_string(),
-            &data,
-        )?));
+        return match read_state_file(&state_file_path) {
+            Ok(state) => Ok(Some(state)),
+            Err(e) => {
+                // The state file may have been corrupted by an interrupted save, so point to the
+                // last good state if there is one. It is not loaded automatically since it may be
+                // missing changes made by the last deployment.
+                let backup_file_path = with_extra_extension(&state_file_path, "bak");
+                if read_state_file(&backup_file_path).is_err() {
+                    return Err(e);
+                }
+                Err(format!(
+                    "{}\nThe last good state was backed up to {}. Any changes made by the last deployment may be missing from it. To use it, replace {} with it.",
+                    e,
+                    backup_file_path.display(),
+                    state_file_path.display()
+                ))
+            }
+        };
     };
 
     Ok(None)
@@ -232,6 +256,313 @@ pub async fn get_previous_state(
     Ok(state)
 }
 
+/// The locale codes which Roblox supports for localized experience titles and descriptions.
+const SUPPORTED_LOCALES: &[&str] = &[
+    "ar", "de", "en", "es", "fr", "id", "it", "ja", "ko", "pl", "pt", "ru", "th", "tr", "vi",
+    "zh-hans", "zh-hant",
+];
+
+/// The regions (ISO 3166-1 alpha-2 country codes) which developer products can be priced in.
+const SUPPORTED_PRICE_REGIONS: &[&str] = &[
+    "AE", "AR", "AU", "BR", "CA", "CH", "CL", "CO", "CZ", "DE", "DK", "EG", "ES", "FI", "FR", "GB",
+    "HK", "ID", "IN", "IT", "JP", "KR", "MX", "MY", "NL", "NO", "NZ", "PE", "PH", "PL", "PT", "RU",
+    "SA", "SE", "SG", "TH", "TR", "TW", "UA", "US", "VN", "ZA",
+];
+
+/// The image formats which can be uploaded as icons and thumbnails. The format is also checked
+/// from the file's contents when it is uploaded.
+const IMAGE_FILE_EXTENSIONS: &[&str] = &["jpeg", "jpg", "png"];
+
+/// Records a problem if the file does not exist, cannot be read, or (when `extensions` is provided)
+/// does not have one of the expected extensions.
+fn validate_file(
+    problems: &mut Vec<String>,
+    project_path: &Path,
+    description: &str,
+    file: &str,
+    extensions: Option<&[&str]>,
+) {
+    if let Err(e) = fs::File::open(project_path.join(file)) {
+        problems.push(format!("{} {} cannot be read: {}", description, file, e));
+        return;
+    }
+
+    if let Some(extensions) = extensions {
+        let extension = Path::new(file)
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(|extension| extension.to_lowercase());
+        if !matches!(extension, Some(extension) if extensions.contains(&extension.as_str())) {
+            problems.push(format!(
+                "{} {} must be one of the following file types: {}",
+                description,
+                file,
+                extensions.join(", ")
+            ));
+        }
+    }
+}
+
+/// Returns a problem for each unavailable region which is not an uppercase two-letter country code
+/// or which is listed more than once.
+fn get_unavailable_region_problems(regions: &[String]) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut seen_regions = Vec::new();
+    for region in regions {
+        if region.len() != 2 || !region.chars().all(|c| c.is_ascii_uppercase()) {
+            problems.push(format!(
+                "Invalid unavailable region {}: expected an uppercase two-letter country code",
+                region
+            ));
+        } else if seen_regions.contains(&region) {
+            problems.push(format!("Duplicate unavailable region {}", region));
+        }
+        seen_regions.push(region);
+    }
+    problems
+}
+
+/// Checks the files and settings referenced by the target config before any resources are
+/// created, updated, or deleted. All problems are reported together so that they can be fixed in
+/// one pass. Unknown enum values are already rejected when the config is loaded.
+pub fn validate_target_config(
+    project_path: &Path,
+    target_config: &TargetConfig,
+) -> Result<(), String> {
+    let TargetConfig::Experience(target_config) = target_config;
+    let mut problems = Vec::new();
+
+    if let Some(places) = &target_config.places {
+        let start_place = target_config.start_place.as_deref().unwrap_or("start");
+        if !places.contains_key(start_place) {
+            problems.push(format!(
+                "No start place specified (expected a place labelled {})",
+                start_place
+            ));
+        }
+
+        for (label, place) in places {
+            if let Some(file) = &place.file {
+                validate_file(
+                    &mut problems,
+                    project_path,
+                    &format!("Place file for place {}", label),
+                    file,
+                    None,
+                );
+                if let Err(e) = PlaceFileFormat::from_path(Path::new(file)) {
+                    problems.push(e.to_string());
+                }
+                if place.version.is_some() {
+                    problems.push(format!(
+                        "Place {} has both a file and a version. Remove the file to roll the place back to the version, or remove the version to upload the file.",
+                        label
+                    ));
+                }
+            }
+            if place.template_place_id.is_some() && label == start_place {
+                problems.push(format!(
+                    "The start place {} cannot have a templatePlaceId because it is created with the experience",
+                    label
+                ));
+            }
+            if let Some(configuration) = &place.configuration {
+                problems.extend(get_place_configuration_length_problems(
+                    label,
+                    configuration.name.as_deref().unwrap_or_default(),
+                    configuration.description.as_deref().unwrap_or_default(),
+                ));
+            }
+            if place
+                .configuration
+                .as_ref()
+                .and_then(|c| c.playable_devices.as_ref())
+                .is_some()
+            {
+                problems.push(format!(
+                    "Unsupported property playableDevices for place {}: Roblox only supports configuring playable devices for the whole experience. Use the experience's configuration.playableDevices property instead.",
+                    label
+                ));
+            }
+        }
+    } else {
+        problems.push("No start place specified".to_owned());
+    }
+
+    if let Some(configuration) = &target_config.configuration {
+        if let Some(unavailable_regions) = &configuration.unavailable_regions {
+            problems.extend(get_unavailable_region_problems(unavailable_regions));
+        }
+    }
+
+    if target_config.icon.is_some() && target_config.icon_url.is_some() {
+        problems.push("The experience cannot have both an icon and an iconUrl".to_owned());
+    }
+    if let Some(icon) = &target_config.icon {
+        validate_file(
+            &mut problems,
+            project_path,
+            "Experience icon",
+            icon,
+            Some(IMAGE_FILE_EXTENSIONS),
+        );
+    }
+
+    for thumbnail in target_config.thumbnails.iter().flatten() {
+        match thumbnail {
+            ThumbnailTargetConfig::File(file)
+            | ThumbnailTargetConfig::Options {
+                file: Some(file),
+                url: None,
+                asset_id: None,
+                ..
+            } => validate_file(
+                &mut problems,
+                project_path,
+                "Experience thumbnail",
+                file,
+                Some(IMAGE_FILE_EXTENSIONS),
+            ),
+            ThumbnailTargetConfig::Options {
+                file: None,
+                url: Some(_),
+                asset_id: None,
+                ..
+            }
+            | ThumbnailTargetConfig::Options {
+                file: None,
+                url: None,
+                asset_id: Some(_),
+                ..
+            } => {}
+            ThumbnailTargetConfig::Options { .. } => problems.push(
+                "Each thumbnail must have exactly one of a file, a url, or an assetId".to_owned(),
+            ),
+        }
+    }
+
+    if get_primary_thumbnail_count(target_config) > 1 {
+        problems.push("At most one thumbnail can be primary".to_owned());
+    }
+
+    for (label, product) in target_config.products.iter().flatten() {
+        if product.icon.is_some() && product.icon_asset_id.is_some() {
+            problems.push(format!(
+                "Product {} has both an icon and an iconAssetId. Specify only one of them.",
+                label
+            ));
+        }
+        if let Some(icon) = &product.icon {
+            validate_file(
+                &mut problems,
+                project_path,
+                &format!("Icon for product {}", label),
+                icon,
+                Some(IMAGE_FILE_EXTENSIONS),
+            );
+        }
+        for region in product
+            .regional_prices
+            .iter()
+            .flat_map(|prices| prices.keys())
+        {
+            if !SUPPORTED_PRICE_REGIONS.contains(&region.as_str()) {
+                problems.push(format!(
+                    "Unsupported price region {} for product {}. Supported regions are: {}",
+                    region,
+                    label,
+                    SUPPORTED_PRICE_REGIONS.join(", ")
+                ));
+            }
+        }
+    }
+
+    for (label, pass) in target_config.passes.iter().flatten() {
+        validate_file(
+            &mut problems,
+            project_path,
+            &format!("Icon for pass {}", label),
+            &pass.icon,
+            Some(IMAGE_FILE_EXTENSIONS),
+        );
+    }
+
+    for (label, subscription) in target_config.subscriptions.iter().flatten() {
+        validate_file(
+            &mut problems,
+            project_path,
+            &format!("Icon for subscription {}", label),
+            &subscription.icon,
+            Some(IMAGE_FILE_EXTENSIONS),
+        );
+    }
+
+    for (label, badge) in target_config.badges.iter().flatten() {
+        validate_file(
+            &mut problems,
+            project_path,
+            &format!("Icon for badge {}", label),
+            &badge.icon,
+            Some(IMAGE_FILE_EXTENSIONS),
+        );
+    }
+
+    for asset in target_config.assets.iter().flatten() {
+        // Glob patterns are checked when they are expanded, since they may match no files.
+        if let AssetTargetConfig::FileWithAlias { file, .. } = asset {
+            validate_file(&mut problems, project_path, "Asset", file, None);
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Found {} problem(s) with the configuration:\n\t{}",
+            problems.len(),
+            problems.join("\n\t")
+        ))
+    }
+}
+
+fn get_primary_thumbnail_count(target_config: &ExperienceTargetConfig) -> usize {
+    target_config
+        .thumbnails
+        .iter()
+        .flatten()
+        .filter(|thumbnail| {
+            matches!(
+                thumbnail,
+                ThumbnailTargetConfig::Options {
+                    primary: Some(true),
+                    ..
+                }
+            )
+        })
+        .count()
+}
+
+fn get_experience_activation(
+    configuration: Option<&ExperienceTargetConfigurationConfig>,
+) -> Result<bool, String> {
+    // Activation is managed separately from the configuration's playability (which only sets
+    // whether the experience is friends-only), so changing one never changes the other.
+    let is_private = matches!(
+        configuration
+            .and_then(|c| c.playability)
+            .unwrap_or(PlayabilityTargetConfig::Private),
+        PlayabilityTargetConfig::Private
+    );
+    match configuration.and_then(|c| c.active) {
+        Some(true) if is_private => Err(
+            "The experience's configuration.active cannot be true when its configuration.playability is private. Set playability to public or friends."
+                .to_owned(),
+        ),
+        Some(active) => Ok(active),
+        None => Ok(!is_private),
+    }
+}
+
 fn get_desired_experience_graph(
     project_path: &Path,
     target_config: &ExperienceTargetConfig,
@@ -246,7 +577,10 @@ fn get_desired_experience_graph(
 
     let experience = RobloxResource::new(
         "experience_singleton",
-        RobloxInputs::Experience(ExperienceInputs { group_id }),
+        RobloxInputs::Experience(ExperienceInputs {
+            group_id,
+            asset_id: target_config.id,
+        }),
         &[],
     );
     resources.push(experience.clone());
@@ -254,48 +588,118 @@ fn get_desired_experience_graph(
     resources.push(RobloxResource::new(
         "experienceActivation_singleton",
         RobloxInputs::ExperienceActivation(ExperienceActivationInputs {
-            is_active: !matches!(
-                target_config
-                    .configuration
-                    .as_ref()
-                    .and_then(|c| c.playability)
-                    .unwrap_or(PlayabilityTargetConfig::Private),
-                PlayabilityTargetConfig::Private
-            ),
+            is_active: get_experience_activation(target_config.configuration.as_ref())?,
         }),
         &[&experience],
     ));
 
+    let mut experience_configuration_resource: Option<RobloxResource> = None;
     if let Some(experience_configuration) = &target_config.configuration {
-        resources.push(RobloxResource::new(
+        let resource = RobloxResource::new(
             "experienceConfiguration_singleton",
             RobloxInputs::ExperienceConfiguration(experience_configuration.into()),
             &[&experience],
-        ));
+        );
+        resources.push(resource.clone());
+        experience_configuration_resource = Some(resource);
     }
 
-    if let Some(places) = &target_config.places {
-        if !places.contains_key("start") {
-            return Err("No start place specified".to_owned());
+    if let Some(configuration) = &target_config.configuration {
+        let settings = PrivateServerSettingsModel {
+            is_link_sharing_enabled: configuration.private_server_link_sharing,
+            is_subscribers_only: configuration.private_servers_subscribers_only,
+        };
+        if settings.requires_private_servers()
+            && matches!(
+                configuration.private_servers,
+                Some(PrivateServersTargetConfig::Disabled)
+            )
+        {
+            return Err(
+                "The experience's configuration.privateServerLinkSharing and configuration.privateServersSubscribersOnly can only be enabled when configuration.privateServers is enabled"
+                    .to_owned(),
+            );
         }
 
+        if settings.is_link_sharing_enabled.is_some() || settings.is_subscribers_only.is_some() {
+            // Apply the private server settings after the configuration which enables private
+            // servers
+            let mut dependencies = vec![&experience];
+            if let Some(resource) = &experience_configuration_resource {
+                dependencies.push(resource);
+            }
+            resources.push(RobloxResource::new(
+                "experiencePrivateServers_singleton",
+                RobloxInputs::ExperiencePrivateServers(settings),
+                &dependencies,
+            ));
+        }
+    }
+
+    if let Some(avatar) = &target_config.avatar {
+        if let Some(avatar_type) = target_config
+            .configuration
+            .as_ref()
+            .and_then(|c| c.avatar_type)
+        {
+            if avatar_type != avatar.avatar_type {
+                return Err(
+                    "The experience's avatar.avatarType must match its configuration.avatarType"
+                        .to_owned(),
+                );
+            }
+        }
+
+        // Apply the avatar settings after the configuration so that they are not overwritten by it
+        let mut dependencies = vec![&experience];
+        if let Some(resource) = &experience_configuration_resource {
+            dependencies.push(resource);
+        }
+        resources.push(RobloxResource::new(
+            "experienceAvatar_singleton",
+            RobloxInputs::ExperienceAvatar(avatar.into()),
+            &dependencies,
+        ));
+    }
+
+    // The places, start place, and each of the other inputs below were already checked by
+    // `validate_target_config`
+    if let Some(places) = &target_config.places {
+        let start_place = target_config.start_place.as_deref().unwrap_or("start");
         for (label, place) in places.iter() {
             let place_resource = RobloxResource::new(
                 &format!("place_{}", label),
                 RobloxInputs::Place(PlaceInputs {
-                    is_start: label == "start",
+                    is_start: label == start_place,
+                    name: place
+                        .configuration
+                        .as_ref()
+                        .and_then(|configuration| configuration.name.clone()),
+                    template_place_id: place.template_place_id,
                 }),
                 &[&experience],
             );
             resources.push(place_resource.clone());
 
-            if let Some(file) = &place.file {
+            let place_file_inputs = match (&place.file, place.version) {
+                (Some(file), _) => Some(PlaceFileInputs {
+                    file_path: Some(file.clone()),
+                    file_hash: Some(compute_file_hash(&project_path.join(file))?),
+                    version: None,
+                    file_format: place.file_format.map(|format| format.into()),
+                }),
+                (None, Some(version)) => Some(PlaceFileInputs {
+                    file_path: None,
+                    file_hash: None,
+                    version: Some(version),
+                    file_format: None,
+                }),
+                (None, None) => None,
+            };
+            if let Some(place_file_inputs) = place_file_inputs {
                 resources.push(RobloxResource::new(
                     &format!("placeFile_{}", label),
-                    RobloxInputs::PlaceFile(FileInputs {
-                        file_path: file.clone(),
-                        file_hash: get_file_hash(project_path.join(file))?,
-                    }),
+                    RobloxInputs::PlaceFile(place_file_inputs),
                     &[&place_resource, &experience],
                 ));
             }
@@ -308,35 +712,100 @@ fn get_desired_experience_graph(
                 ));
             }
         }
-    } else {
-        return Err("No start place specified".to_owned());
     }
 
-    if let Some(icon_path) = &target_config.icon {
+    let icon_inputs = match (&target_config.icon, &target_config.icon_url) {
+        (Some(icon_path), _) => Some(ExperienceIconInputs {
+            file_path: Some(icon_path.clone()),
+            url: None,
+            file_hash: compute_file_hash(&project_path.join(icon_path))?,
+        }),
+        (None, Some(icon_url)) => Some(ExperienceIconInputs {
+            file_path: None,
+            url: Some(icon_url.to_string()),
+            file_hash: compute_file_hash(&get_remote_image_path(icon_url.as_str()))?,
+        }),
+        (None, None) => None,
+    };
+    if let Some(icon_inputs) = icon_inputs {
         resources.push(RobloxResource::new(
             "experienceIcon_singleton",
-            RobloxInputs::ExperienceIcon(FileInputs {
-                file_path: icon_path.clone(),
-                file_hash: get_file_hash(project_path.join(icon_path))?,
-            }),
+            RobloxInputs::ExperienceIcon(icon_inputs),
             &[&experience],
         ));
     }
 
     if let Some(thumbnails) = &target_config.thumbnails {
         let mut thumbnail_resources: Vec<RobloxResource> = Vec::new();
-        for thumbnail_path in thumbnails {
+        let mut primary_thumbnail_index: Option<usize> = None;
+        for thumbnail in thumbnails {
+            if let ThumbnailTargetConfig::Options {
+                primary: Some(true),
+                ..
+            } = thumbnail
+            {
+                primary_thumbnail_index = Some(thumbnail_resources.len());
+            }
+            let (id, inputs) = match thumbnail {
+                ThumbnailTargetConfig::File(file)
+                | ThumbnailTargetConfig::Options {
+                    file: Some(file),
+                    url: None,
+                    asset_id: None,
+                    ..
+                } => (
+                    file.clone(),
+                    ExperienceThumbnailInputs {
+                        file_path: Some(file.clone()),
+                        url: None,
+                        file_hash: Some(compute_file_hash(&project_path.join(file))?),
+                        asset_id: None,
+                    },
+                ),
+                ThumbnailTargetConfig::Options {
+                    file: None,
+                    url: Some(url),
+                    asset_id: None,
+                    ..
+                } => (
+                    url.to_string(),
+                    ExperienceThumbnailInputs {
+                        file_path: None,
+                        url: Some(url.to_string()),
+                        file_hash: Some(compute_file_hash(&get_remote_image_path(url.as_str()))?),
+                        asset_id: None,
+                    },
+                ),
+                ThumbnailTargetConfig::Options {
+                    file: None,
+                    url: None,
+                    asset_id: Some(asset_id),
+                    ..
+                } => (
+                    format!("asset_{}", asset_id),
+                    ExperienceThumbnailInputs {
+                        file_path: None,
+                        url: None,
+                        file_hash: None,
+                        asset_id: Some(*asset_id),
+                    },
+                ),
+                ThumbnailTargetConfig::Options { .. } => unreachable!(),
+            };
             thumbnail_resources.push(RobloxResource::new(
-                &format!("experienceThumbnail_{}", thumbnail_path),
-                RobloxInputs::ExperienceThumbnail(FileInputs {
-                    file_path: thumbnail_path.clone(),
-                    file_hash: get_file_hash(project_path.join(thumbnail_path))?,
-                }),
+                &format!("experienceThumbnail_{}", id),
+                RobloxInputs::ExperienceThumbnail(inputs),
                 &[&experience],
             ));
         }
+        // The thumbnail order follows the order of the dependencies, and the first thumbnail is
+        // the primary thumbnail
         let mut thumbnail_order_dependencies: Vec<&RobloxResource> =
             thumbnail_resources.iter().collect();
+        if let Some(index) = primary_thumbnail_index {
+            let primary_thumbnail = thumbnail_order_dependencies.remove(index);
+            thumbnail_order_dependencies.insert(0, primary_thumbnail);
+        }
         thumbnail_order_dependencies.push(&experience);
         resources.push(RobloxResource::new(
             "experienceThumbnailOrder_singleton",
@@ -388,19 +857,38 @@ fn get_desired_experience_graph(
                     name: product.name.clone(),
                     description: product.description.clone().unwrap_or_default(),
                     price: product.price,
+                    regional_prices: product
+                        .regional_prices
+                        .as_ref()
+                        .map(|regional_prices| regional_prices.clone().into_iter().collect()),
                 }),
                 &[&experience],
             );
 
-            if let Some(icon_path) = &product.icon {
-                resources.push(RobloxResource::new(
-                    &format!("productIcon_{}", label),
-                    RobloxInputs::ProductIcon(FileInputs {
-                        file_path: icon_path.clone(),
-                        file_hash: get_file_hash(project_path.join(icon_path))?,
-                    }),
-                    &[&product_resource],
-                ));
+            match (&product.icon, product.icon_asset_id) {
+                (Some(icon_path), _) => {
+                    resources.push(RobloxResource::new(
+                        &format!("productIcon_{}", label),
+                        RobloxInputs::ProductIcon(ProductIconInputs {
+                            file_path: Some(icon_path.clone()),
+                            file_hash: Some(compute_file_hash(&project_path.join(icon_path))?),
+                            asset_id: None,
+                        }),
+                        &[&product_resource],
+                    ));
+                }
+                (None, Some(asset_id)) => {
+                    resources.push(RobloxResource::new(
+                        &format!("productIcon_{}", label),
+                        RobloxInputs::ProductIcon(ProductIconInputs {
+                            file_path: None,
+                            file_hash: None,
+                            asset_id: Some(asset_id),
+                        }),
+                        &[&product_resource, &experience],
+                    ));
+                }
+                (None, None) => {}
             }
 
             resources.push(product_resource);
@@ -416,7 +904,30 @@ fn get_desired_experience_graph(
                     description: pass.description.clone().unwrap_or_default(),
                     price: pass.price,
                     icon_file_path: pass.icon.clone(),
-                    icon_file_hash: get_file_hash(project_path.join(pass.icon.clone()))?,
+                    icon_file_hash: compute_file_hash(&project_path.join(pass.icon.clone()))?,
+                }),
+                &[&experience],
+            ));
+        }
+    }
+
+    if let Some(subscriptions) = &target_config.subscriptions {
+        for (label, subscription) in subscriptions {
+            resources.push(RobloxResource::new(
+                &format!("subscription_{}", label),
+                RobloxInputs::Subscription(SubscriptionInputs {
+                    name: subscription.name.clone(),
+                    description: subscription.description.clone().unwrap_or_default(),
+                    price: subscription.price,
+                    period: match subscription.period {
+                        Some(SubscriptionPeriodTargetConfig::Monthly) | None => {
+                            DeveloperSubscriptionPeriod::Monthly
+                        }
+                    },
+                    icon_file_path: subscription.icon.clone(),
+                    icon_file_hash: compute_file_hash(
+                        &project_path.join(subscription.icon.clone()),
+                    )?,
                 }),
                 &[&experience],
             ));
@@ -439,7 +950,7 @@ fn get_desired_experience_graph(
                 &format!("badgeIcon_{}", label),
                 RobloxInputs::BadgeIcon(FileInputs {
                     file_path: badge.icon.clone(),
-                    file_hash: get_file_hash(project_path.join(badge.icon.clone()))?,
+                    file_hash: compute_file_hash(&project_path.join(badge.icon.clone()))?,
                 }),
                 &[&badge_resource],
             ));
@@ -492,13 +1003,13 @@ fn get_desired_experience_graph(
                     Some(Some("bmp" | "gif" | "jpeg" | "jpg" | "png" | "tga")) => {
                         RobloxInputs::ImageAsset(FileWithGroupIdInputs {
                             file_path: file.clone(),
-                            file_hash: get_file_hash(project_path.join(&file))?,
+                            file_hash: compute_file_hash(&project_path.join(&file))?,
                             group_id,
                         })
                     }
                     Some(Some("ogg" | "mp3")) => RobloxInputs::AudioAsset(FileWithGroupIdInputs {
                         file_path: file.clone(),
-                        file_hash: get_file_hash(project_path.join(&file))?,
+                        file_hash: compute_file_hash(&project_path.join(&file))?,
                         group_id,
                     }),
                     _ => return Err(format!("Unable to determine asset type for file: {}", file)),
@@ -552,14 +1063,70 @@ fn get_desired_experience_graph(
         }
     }
 
+    if let Some(collaborators) = &target_config.collaborators {
+        let mut permissions = Vec::new();
+        for collaborator in collaborators {
+            let (subject_type, subject_id) = match (collaborator.user, collaborator.group_role) {
+                (Some(user), None) => (ExperienceCollaboratorSubjectType::User, user),
+                (None, Some(group_role)) => {
+                    (ExperienceCollaboratorSubjectType::GroupRoleset, group_role)
+                }
+                _ => {
+                    return Err(
+                        "Each collaborator must have exactly one of user or groupRole".to_owned(),
+                    )
+                }
+            };
+            permissions.push(ExperienceCollaboratorPermission {
+                subject_type,
+                subject_id,
+                action: match collaborator.access {
+                    CollaboratorAccessTargetConfig::Play => ExperienceCollaboratorAction::Play,
+                    CollaboratorAccessTargetConfig::Edit => ExperienceCollaboratorAction::Edit,
+                },
+            });
+        }
+
+        resources.push(RobloxResource::new(
+            "experienceCollaborators_singleton",
+            RobloxInputs::ExperienceCollaborators(ExperienceCollaboratorsInputs { permissions }),
+            &[&experience],
+        ));
+    }
+
+    if let Some(locales) = &target_config.locales {
+        for (locale, locale_config) in locales {
+            if !SUPPORTED_LOCALES.contains(&locale.as_str()) {
+                return Err(format!(
+                    "Unsupported locale {}. Supported locales are: {}",
+                    locale,
+                    SUPPORTED_LOCALES.join(", ")
+                ));
+            }
+
+            resources.push(RobloxResource::new(
+                &format!("experienceLocalization_{}", locale),
+                RobloxInputs::ExperienceLocalization(ExperienceLocalizationInputs {
+                    locale: locale.clone(),
+                    title: locale_config.title.clone(),
+                    description: locale_config.description.clone(),
+                }),
+                &[&experience],
+            ));
+        }
+    }
+
     Ok(ResourceGraph::new(&resources))
 }
 
-pub fn get_desired_graph(
+pub async fn get_desired_graph(
     project_path: &Path,
     target_config: &TargetConfig,
     owner_config: &OwnerConfig,
 ) -> Result<ResourceGraph<RobloxResource, RobloxInputs, RobloxOutputs>, String> {
+    validate_target_config(project_path, target_config)?;
+    download_remote_images(target_config).await?;
+
     match target_config {
         TargetConfig::Experience(experience_target_config) => {
             get_desired_experience_graph(project_path, experience_target_config, owner_config)
@@ -567,6 +1134,52 @@ pub fn get_desired_graph(
     }
 }
 
+/// Gets the resource ID of each imported place, in the same order as the places.
+fn get_imported_place_resource_ids(
+    target_id: AssetId,
+    start_place_id: AssetId,
+    places: &[GetPlaceResponse],
+) -> Result<Vec<String>, String> {
+    // The experience's root place is the source of truth for which place is the start place, so
+    // that the start place resource always refers to the experience's `start_place_id` output.
+    if !places.iter().any(|place| place.id == start_place_id) {
+        return Err(format!(
+            "Start place {} of experience {} was not found in the experience's places",
+            start_place_id, target_id
+        ));
+    }
+    Ok(places
+        .iter()
+        .map(|place| {
+            if place.id == start_place_id {
+                "start".to_owned()
+            } else {
+                place.id.to_string()
+            }
+        })
+        .collect())
+}
+
+/// Warns if an imported experience is archived, since it will stay hidden from players after
+/// deploying, and records the archived state in its outputs.
+fn get_imported_experience_outputs(
+    target_id: AssetId,
+    experience: &GetExperienceResponse,
+) -> ExperienceOutputs {
+    if experience.is_archived {
+        logger::log(Paint::yellow(format!(
+            "Experience {} is archived, so it is hidden from players. Unarchive it on the Creator Dashboard before deploying to it.",
+            target_id
+        )));
+    }
+
+    ExperienceOutputs {
+        asset_id: target_id,
+        start_place_id: experience.root_place_id,
+        is_archived: experience.is_archived,
+    }
+}
+
 pub async fn import_graph(
     roblox_api: &RobloxApi,
     target_id: AssetId,
@@ -574,12 +1187,15 @@ pub async fn import_graph(
     let mut resources: Vec<RobloxResource> = Vec::new();
 
     logger::log("Importing experience");
+    let experience_response = roblox_api.get_experience(target_id).await?;
+    let experience_outputs = get_imported_experience_outputs(target_id, &experience_response);
     let GetExperienceResponse {
         root_place_id: start_place_id,
         is_active: is_experience_active,
         creator_target_id,
         creator_type,
-    } = roblox_api.get_experience(target_id).await?;
+        ..
+    } = experience_response;
 
     let group_id = match creator_type {
         CreatorType::User => None,
@@ -588,11 +1204,11 @@ pub async fn import_graph(
 
     let experience = RobloxResource::existing(
         "experience_singleton",
-        RobloxInputs::Experience(ExperienceInputs { group_id }),
-        RobloxOutputs::Experience(ExperienceOutputs {
-            asset_id: target_id,
-            start_place_id,
+        RobloxInputs::Experience(ExperienceInputs {
+            group_id,
+            asset_id: None,
         }),
+        RobloxOutputs::Experience(experience_outputs),
         &[],
     );
     resources.push(experience.clone());
@@ -610,8 +1226,11 @@ pub async fn import_graph(
     let experience_configuration = roblox_api.get_experience_configuration(target_id).await?;
     resources.push(RobloxResource::existing(
         "experienceConfiguration_singleton",
-        RobloxInputs::ExperienceConfiguration(experience_configuration),
-        RobloxOutputs::ExperienceConfiguration,
+        RobloxInputs::ExperienceConfiguration(experience_configuration.clone()),
+        RobloxOutputs::ExperienceConfiguration(Box::new(ExperienceConfigurationOutputs {
+            experience_id: Some(target_id),
+            applied_configuration: Some(experience_configuration),
+        })),
         &[&experience],
     ));
 
@@ -621,12 +1240,14 @@ pub async fn import_graph(
     logger::log("Importing experience thumbnails");
     let thumbnails = roblox_api.get_experience_thumbnails(target_id).await?;
     let mut thumbnail_resources: Vec<RobloxResource> = Vec::new();
-    for thumbnail in thumbnails {
+    for thumbnail in &thumbnails {
         thumbnail_resources.push(RobloxResource::existing(
             &format!("experienceThumbnail_{}", thumbnail.id),
-            RobloxInputs::ExperienceThumbnail(FileInputs {
-                file_path: "fake-path".to_owned(),
-                file_hash: "fake-hash".to_owned(),
+            RobloxInputs::ExperienceThumbnail(ExperienceThumbnailInputs {
+                file_path: Some("fake-path".to_owned()),
+                url: None,
+                file_hash: Some("fake-hash".to_owned()),
+                asset_id: None,
             }),
             RobloxOutputs::ExperienceThumbnail(AssetOutputs {
                 asset_id: thumbnail.id,
@@ -640,24 +1261,25 @@ pub async fn import_graph(
     resources.push(RobloxResource::existing(
         "experienceThumbnailOrder_singleton",
         RobloxInputs::ExperienceThumbnailOrder,
-        RobloxOutputs::ExperienceThumbnailOrder,
+        RobloxOutputs::ExperienceThumbnailOrder(ExperienceThumbnailOrderOutputs {
+            asset_ids: thumbnails.iter().map(|t| t.id).collect(),
+        }),
         &thumbnail_order_dependencies,
     ));
     resources.extend(thumbnail_resources);
 
     logger::log("Importing places");
-    let places = roblox_api.get_all_places(target_id).await?;
-    for place in places {
-        let resource_id = if place.is_root_place {
-            "start".to_owned()
-        } else {
-            place.id.to_string()
-        };
+    let places = roblox_api.list_places(target_id).await?;
+    let resource_ids = get_imported_place_resource_ids(target_id, start_place_id, &places)?;
+    for (place, resource_id) in places.into_iter().zip(resource_ids) {
+        let is_start = place.id == start_place_id;
 
         let place_resource = RobloxResource::existing(
             &format!("place_{}", resource_id),
             RobloxInputs::Place(PlaceInputs {
-                is_start: place.is_root_place,
+                is_start,
+                name: Some(place.name.clone()),
+                template_place_id: None,
             }),
             RobloxOutputs::Place(AssetOutputs { asset_id: place.id }),
             &[&experience],
@@ -666,20 +1288,29 @@ pub async fn import_graph(
 
         resources.push(RobloxResource::existing(
             &format!("placeFile_{}", resource_id),
-            RobloxInputs::PlaceFile(FileInputs {
-                file_path: "fake-path".to_owned(),
-                file_hash: "fake-hash".to_owned(),
+            RobloxInputs::PlaceFile(PlaceFileInputs {
+                file_path: Some("fake-path".to_owned()),
+                file_hash: Some("fake-hash".to_owned()),
+                version: None,
+                file_format: None,
             }),
             RobloxOutputs::PlaceFile(PlaceFileOutputs {
                 version: place.current_saved_version,
+                place_id: Some(place.id),
+                file_hash: None,
             }),
             &[&place_resource, &experience],
         ));
 
+        let place_id = place.id;
+        let place_configuration: PlaceConfigurationModel = place.into();
         resources.push(RobloxResource::existing(
             &format!("placeConfiguration_{}", resource_id),
-            RobloxInputs::PlaceConfiguration(place.into()),
-            RobloxOutputs::PlaceConfiguration,
+            RobloxInputs::PlaceConfiguration(place_configuration.clone()),
+            RobloxOutputs::PlaceConfiguration(PlaceConfigurationOutputs {
+                place_id: Some(place_id),
+                applied_configuration: Some(place_configuration),
+            }),
             &[&place_resource],
         ));
     }
@@ -714,6 +1345,7 @@ pub async fn import_graph(
                 name: product.name,
                 description: product.description.unwrap_or_default(),
                 price: product.price_in_robux,
+                regional_prices: None,
             }),
             RobloxOutputs::Product(ProductOutputs {
                 asset_id: product.product_id,
@@ -724,9 +1356,10 @@ pub async fn import_graph(
         if let Some(icon_id) = product.icon_image_asset_id {
             resources.push(RobloxResource::existing(
                 &format!("productIcon_{}", product.product_id),
-                RobloxInputs::ProductIcon(FileInputs {
-                    file_path: "fake-path".to_owned(),
-                    file_hash: "fake-hash".to_owned(),
+                RobloxInputs::ProductIcon(ProductIconInputs {
+                    file_path: Some("fake-path".to_owned()),
+                    file_hash: Some("fake-hash".to_owned()),
+                    asset_id: None,
                 }),
                 RobloxOutputs::ProductIcon(AssetOutputs { asset_id: icon_id }),
                 &[&product_resource],
@@ -778,8 +1411,9 @@ pub async fn import_graph(
                 file_path: "fake-path".to_owned(),
                 file_hash: "fake-hash".to_owned(),
             }),
-            RobloxOutputs::BadgeIcon(AssetOutputs {
+            RobloxOutputs::BadgeIcon(BadgeIconOutputs {
                 asset_id: badge.icon_image_id,
+                file_hash: None,
             }),
             &[&badge_resource],
         ));
@@ -863,6 +1497,31 @@ pub async fn import_graph(
         ));
     }
 
+    logger::log("Importing localized titles and descriptions");
+    let localized_metadata = roblox_api
+        .list_experience_localized_metadata(target_id)
+        .await?;
+    for metadata in localized_metadata {
+        // Skip languages which cannot be configured as a locale so that the imported state
+        // matches what a config could produce
+        if !SUPPORTED_LOCALES.contains(&metadata.language_code.as_str()) {
+            continue;
+        }
+
+        resources.push(RobloxResource::existing(
+            &format!("experienceLocalization_{}", metadata.language_code),
+            RobloxInputs::ExperienceLocalization(ExperienceLocalizationInputs {
+                locale: metadata.language_code.clone(),
+                title: metadata.name,
+                description: metadata.description,
+            }),
+            RobloxOutputs::ExperienceLocalization(ExperienceLocalizationOutputs {
+                locale: metadata.language_code,
+            }),
+            &[&experience],
+        ));
+    }
+
     Ok(ResourceGraph::new(&resources))
 }
 
@@ -895,34 +1554,71 @@ pub fn save_state_to_file(
         Paint::cyan(state_file_path.display())
     ));
 
-    fs::write(&state_file_path, data).map_err(|e| {
+    let write_error = |e: std::io::Error| {
         format!(
             "Unable to write state file: {}\n\t{}",
             state_file_path.display(),
             e
         )
-    })?;
+    };
+
+    // Keep a copy of the current state file (if it can be loaded) so that it can be used if the
+    // new state file is somehow corrupted
+    if state_file_path.exists() && read_state_file(&state_file_path).is_ok() {
+        fs::copy(
+            &state_file_path,
+            with_extra_extension(&state_file_path, "bak"),
+        )
+        .map_err(write_error)?;
+    }
+
+    // Write the state to a temporary file and then move it into place so that an interrupted save
+    // cannot leave a truncated state file behind
+    let temp_file_path = with_extra_extension(&state_file_path, "tmp");
+    let mut temp_file = fs::File::create(&temp_file_path).map_err(write_error)?;
+    temp_file.write_all(data).map_err(write_error)?;
+    temp_file.sync_all().map_err(write_error)?;
+    drop(temp_file);
+    fs::rename(&temp_file_path, &state_file_path).map_err(write_error)?;
 
     Ok(())
 }
 
+/// Returns a copy of the state with each environment's resources sorted by ID. The resources are
+/// otherwise listed in the order they were evaluated, which can change between deployments even
+/// when none of the resources have changed.
+fn normalize_state(state: &ResourceStateVLatest) -> ResourceStateVLatest {
+    let mut state = state.to_owned();
+    for resources in state.environments.values_mut() {
+        resources.sort_by_key(|resource| resource.get_id());
+    }
+    state
+}
+
+/// Serializes the state so that saving an unchanged state produces identical bytes (unless it is
+/// encrypted), which keeps version controlled state files free of spurious diffs.
 fn serialize_state(state: &ResourceStateVLatest) -> Result<Vec<u8>, String> {
-    let utc = Utc::now();
     let mut data = format!("#\n\
                                    # WARNING - Generated file. Do not modify directly unless you know \
                                      what you are doing!\n\
-                                   # This file was generated by Mantle v{} on {}\n\
+                                   # This file was generated by Mantle v{}\n\
                                    #\n\n",
-                                crate_version!(),
-                                utc.format("%FT%TZ")
+                                crate_version!()
                             ).as_bytes().to_vec();
 
     let state_data = serde_yaml::to_vec(&ResourceState::Versioned(VersionedResourceState::V6(
-        state.to_owned(),
+        normalize_state(state),
     )))
     .map_err(|e| format!("Unable to serialize state\n\t{}", e))?;
 
-    data.extend(state_data);
+    if EncryptedState::is_enabled() {
+        let encrypted_state = EncryptedState::encrypt(&state_data)?;
+        let encrypted_data = serde_yaml::to_vec(&encrypted_state)
+            .map_err(|e| format!("Unable to serialize encrypted state\n\t{}", e))?;
+        data.extend(encrypted_data);
+    } else {
+        data.extend(state_data);
+    }
 
     Ok(data)
 }
@@ -934,9 +1630,320 @@ pub async fn save_state(
 ) -> Result<(), String> {
     let data = serialize_state(state)?;
 
+    // Make sure the state can be loaded again before replacing the previous state
+    parse_state(
+        "new state",
+        str::from_utf8(&data).map_err(|e| format!("Unable to serialize state\n\t{}", e))?,
+    )?;
+
     match state_config {
         StateConfig::Local => save_state_to_file(project_path, &data, None),
         StateConfig::LocalKey(key) => save_state_to_file(project_path, &data, Some(key)),
         StateConfig::Remote(config) => save_state_to_remote(config, &data).await,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use rbx_api::models::SocialSlotType;
+
+    use super::*;
+
+    fn get_configuration(yaml: &str) -> ExperienceTargetConfigurationConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn experience_activation_defaults_to_playability() {
+        assert!(!get_experience_activation(None).unwrap());
+        assert!(
+            !get_experience_activation(Some(&get_configuration("playability: private"))).unwrap()
+        );
+        assert!(
+            get_experience_activation(Some(&get_configuration("playability: public"))).unwrap()
+        );
+        assert!(
+            get_experience_activation(Some(&get_configuration("playability: friends"))).unwrap()
+        );
+    }
+
+    #[test]
+    fn experience_activation_can_deactivate_public_experiences() {
+        let configuration = get_configuration("playability: public\nactive: false");
+
+        assert!(!get_experience_activation(Some(&configuration)).unwrap());
+    }
+
+    #[test]
+    fn experience_activation_rejects_active_private_experiences() {
+        let configuration = get_configuration("playability: private\nactive: true");
+
+        assert!(get_experience_activation(Some(&configuration)).is_err());
+    }
+
+    #[test]
+    fn imported_archived_experiences_are_surfaced_in_outputs() {
+        let response: GetExperienceResponse = serde_yaml::from_str(
+            r#"{"rootPlaceId":2,"isActive":false,"isArchived":true,"creatorType":"User","creatorTargetId":3}"#,
+        )
+        .unwrap();
+
+        let outputs = get_imported_experience_outputs(1, &response);
+
+        assert!(outputs.is_archived);
+        assert_eq!(
+            serde_yaml::to_value(&outputs).unwrap()["isArchived"],
+            serde_yaml::Value::Bool(true)
+        );
+    }
+
+    #[test]
+    fn imported_experiences_without_an_archived_state_are_live() {
+        let response: GetExperienceResponse = serde_yaml::from_str(
+            r#"{"rootPlaceId":2,"isActive":true,"creatorType":"Group","creatorTargetId":3}"#,
+        )
+        .unwrap();
+
+        let outputs = get_imported_experience_outputs(1, &response);
+
+        assert!(!outputs.is_archived);
+        assert!(serde_yaml::to_value(&outputs).unwrap()["isArchived"].is_null());
+    }
+
+    fn get_state_test_dir(name: &str) -> PathBuf {
+        let dir =
+            env::temp_dir().join(format!("mantle-test-state-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn get_state_data(environment: &str) -> Vec<u8> {
+        serialize_state(&ResourceStateVLatest {
+            environments: BTreeMap::from([(environment.to_owned(), Vec::new())]),
+        })
+        .unwrap()
+    }
+
+    fn load_environments(project_path: &Path) -> Result<Vec<String>, String> {
+        match get_state_from_file(project_path, None)? {
+            Some(ResourceState::Versioned(VersionedResourceState::V6(state))) => {
+                Ok(state.environments.into_keys().collect())
+            }
+            _ => panic!("expected a v6 state file"),
+        }
+    }
+
+    #[test]
+    fn state_files_are_replaced_through_a_temporary_file() {
+        let project_path = get_state_test_dir("atomic");
+        let state_file_path = get_state_file_path(&project_path, None);
+        // A temporary file left behind by an interrupted save
+        fs::write(with_extra_extension(&state_file_path, "tmp"), "environ").unwrap();
+
+        save_state_to_file(&project_path, &get_state_data("dev"), None).unwrap();
+
+        assert_eq!(fs::read(&state_file_path).unwrap(), get_state_data("dev"));
+        assert!(!with_extra_extension(&state_file_path, "tmp").exists());
+        assert_eq!(load_environments(&project_path).unwrap(), vec!["dev"]);
+    }
+
+    #[test]
+    fn saving_state_backs_up_the_previous_state() {
+        let project_path = get_state_test_dir("backup");
+        let backup_file_path =
+            with_extra_extension(&get_state_file_path(&project_path, None), "bak");
+
+        save_state_to_file(&project_path, &get_state_data("dev"), None).unwrap();
+        assert!(!backup_file_path.exists());
+
+        save_state_to_file(&project_path, &get_state_data("prod"), None).unwrap();
+        assert_eq!(fs::read(&backup_file_path).unwrap(), get_state_data("dev"));
+        assert_eq!(load_environments(&project_path).unwrap(), vec!["prod"]);
+    }
+
+    #[test]
+    fn saving_state_does_not_back_up_a_corrupt_state() {
+        let project_path = get_state_test_dir("corrupt-backup");
+        let state_file_path = get_state_file_path(&project_path, None);
+        let backup_file_path = with_extra_extension(&state_file_path, "bak");
+        save_state_to_file(&project_path, &get_state_data("dev"), None).unwrap();
+        save_state_to_file(&project_path, &get_state_data("prod"), None).unwrap();
+
+        fs::write(&state_file_path, "environments: [").unwrap();
+        save_state_to_file(&project_path, &get_state_data("test"), None).unwrap();
+
+        assert_eq!(fs::read(&backup_file_path).unwrap(), get_state_data("dev"));
+    }
+
+    #[test]
+    fn loading_a_corrupt_state_file_points_to_the_backup() {
+        let project_path = get_state_test_dir("corrupt");
+        let state_file_path = get_state_file_path(&project_path, None);
+        save_state_to_file(&project_path, &get_state_data("dev"), None).unwrap();
+        save_state_to_file(&project_path, &get_state_data("prod"), None).unwrap();
+
+        fs::write(&state_file_path, "environments: [").unwrap();
+
+        let error = load_environments(&project_path).unwrap_err();
+        assert!(error.contains(&format!(
+            "backed up to {}",
+            with_extra_extension(&state_file_path, "bak").display()
+        )));
+    }
+
+    #[test]
+    fn loading_a_corrupt_state_file_without_a_backup_fails() {
+        let project_path = get_state_test_dir("corrupt-no-backup");
+        fs::write(get_state_file_path(&project_path, None), "environments: [").unwrap();
+
+        let error = load_environments(&project_path).unwrap_err();
+        assert!(error.starts_with("Unable to parse state file"));
+        assert!(!error.contains("backed up"));
+    }
+
+    #[test]
+    fn validate_target_config_reports_every_problem_at_once() {
+        let target_config = TargetConfig::Experience(
+            serde_yaml::from_str(
+                r#"
+places:
+  start:
+    file: missing-place.rbxl
+    version: 3
+icon: missing-icon.png
+iconUrl: https://example.com/icon.png
+products:
+  gold:
+    name: Gold
+    price: 100
+    regionalPrices:
+      XX: 200
+"#,
+            )
+            .unwrap(),
+        );
+
+        let error =
+            validate_target_config(&get_state_test_dir("validate"), &target_config).unwrap_err();
+
+        assert!(error.starts_with("Found 5 problem(s) with the configuration:"));
+        assert!(error.contains("Place file for place start missing-place.rbxl cannot be read"));
+        assert!(error.contains("Place start has both a file and a version"));
+        assert!(error.contains("The experience cannot have both an icon and an iconUrl"));
+        assert!(error.contains("Experience icon missing-icon.png cannot be read"));
+        assert!(error.contains("Unsupported price region XX for product gold"));
+    }
+
+    #[test]
+    fn validate_target_config_accepts_valid_configs() {
+        let project_path = get_state_test_dir("validate-valid");
+        fs::write(project_path.join("start.rbxl"), "").unwrap();
+        let target_config = TargetConfig::Experience(
+            serde_yaml::from_str("places:\n  start:\n    file: start.rbxl").unwrap(),
+        );
+
+        assert!(validate_target_config(&project_path, &target_config).is_ok());
+    }
+
+    fn get_place(id: AssetId, is_root_place: bool) -> GetPlaceResponse {
+        GetPlaceResponse {
+            id,
+            current_saved_version: 1,
+            name: format!("Place {}", id),
+            description: String::new(),
+            max_player_count: 50,
+            allow_copying: false,
+            social_slot_type: SocialSlotType::Automatic,
+            custom_social_slots_count: None,
+            is_root_place,
+        }
+    }
+
+    #[test]
+    fn imported_start_place_uses_experience_root_place_id() {
+        // The place list may disagree with the experience about which place is the root place
+        let places = vec![get_place(1, true), get_place(2, false)];
+
+        assert_eq!(
+            get_imported_place_resource_ids(100, 2, &places).unwrap(),
+            vec!["1".to_owned(), "start".to_owned()]
+        );
+    }
+
+    #[test]
+    fn imported_start_place_must_be_in_experience_places() {
+        let places = vec![get_place(1, true), get_place(2, false)];
+
+        assert!(get_imported_place_resource_ids(100, 3, &places).is_err());
+    }
+
+    fn get_regions(regions: &[&str]) -> Vec<String> {
+        regions.iter().map(|region| region.to_string()).collect()
+    }
+
+    #[test]
+    fn unavailable_regions_accept_country_codes() {
+        assert!(get_unavailable_region_problems(&get_regions(&["BE", "NL"])).is_empty());
+        assert!(get_unavailable_region_problems(&[]).is_empty());
+    }
+
+    #[test]
+    fn unavailable_regions_reject_invalid_and_duplicate_codes() {
+        assert_eq!(
+            get_unavailable_region_problems(&get_regions(&["be", "BEL", "NL", "NL"])),
+            vec![
+                "Invalid unavailable region be: expected an uppercase two-letter country code",
+                "Invalid unavailable region BEL: expected an uppercase two-letter country code",
+                "Duplicate unavailable region NL",
+            ]
+        );
+    }
+
+    fn get_state(resources: Vec<RobloxResource>) -> ResourceStateVLatest {
+        ResourceStateVLatest {
+            environments: BTreeMap::from([("production".to_owned(), resources)]),
+        }
+    }
+
+    #[test]
+    fn serialized_state_does_not_depend_on_resource_order() {
+        let experience = RobloxResource::new(
+            "experience_singleton",
+            RobloxInputs::Experience(ExperienceInputs {
+                group_id: None,
+                asset_id: None,
+            }),
+            &[],
+        );
+        let activation = RobloxResource::new(
+            "experienceActivation_singleton",
+            RobloxInputs::ExperienceActivation(ExperienceActivationInputs { is_active: true }),
+            &[&experience],
+        );
+
+        let state =
+            serialize_state(&get_state(vec![experience.clone(), activation.clone()])).unwrap();
+        let reordered_state = serialize_state(&get_state(vec![activation, experience])).unwrap();
+
+        assert_eq!(state, reordered_state);
+    }
+
+    #[test]
+    fn serialized_state_does_not_include_the_time() {
+        let state = get_state(Vec::new());
+
+        // Saving the same state later must produce the same file
+        assert_eq!(
+            serialize_state(&state).unwrap(),
+            serialize_state(&state).unwrap()
+        );
+        let data = String::from_utf8(serialize_state(&state).unwrap()).unwrap();
+        assert!(data.contains(&format!(
+            "# This file was generated by Mantle v{}\n",
+            crate_version!()
+        )));
+    }
+}
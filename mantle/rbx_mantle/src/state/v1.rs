@@ -1,10 +1,8 @@
 use std::collections::HashMap;
 
-use rbx_api::{
-    experiences::models::ExperienceConfigurationModel, places::models::PlaceConfigurationModel,
-};
+use rbx_api::places::models::PlaceConfigurationModel;
 use serde::{Deserialize, Serialize};
-use serde_yaml::{to_value, Mapping, Value};
+use serde_yaml::{to_value, Value};
 
 use super::{
     legacy_resources::{Input, LegacyResource},
@@ -55,100 +53,6 @@ impl From<ResourceStateV1> for ResourceStateV2 {
                         .insert("groupId".to_owned(), Input::Value(Value::Null));
                 }
 
-                // Resources format change: add missing experience configuration defaults.
-                // Previously, the ExperienceConfigurationModel allowed all options to be missing,
-                // but the new version requires that all options be set.
-                if matches!(r_type, "experienceConfiguration") {
-                    if let Input::Value(configuration) =
-                        resource.inputs.get_mut("configuration").unwrap()
-                    {
-                        let default_model = ExperienceConfigurationModel::default();
-                        let configuration = configuration.as_mapping_mut().unwrap();
-                        provide_default!(configuration, "genre", default_model.genre);
-                        provide_default!(
-                            configuration,
-                            "playableDevices",
-                            default_model.playable_devices
-                        );
-                        provide_default!(
-                            configuration,
-                            "allowPrivateServers",
-                            default_model.allow_private_servers
-                        );
-                        provide_default!(configuration, "isForSale", default_model.is_for_sale);
-                        provide_default!(
-                            configuration,
-                            "studioAccessToApisAllowed",
-                            default_model.studio_access_to_apis_allowed
-                        );
-                        if !provide_default!(
-                            configuration,
-                            "studioAccessToApisAllowed",
-                            default_model.studio_access_to_apis_allowed
-                        ) {
-                            let permissions = match configuration
-                                .get_mut(&Value::String("permissions".to_owned()))
-                                .unwrap()
-                            {
-                                Value::Null => {
-                                    configuration.insert(
-                                        Value::String("permissions".to_owned()),
-                                        Value::Mapping(Mapping::new()),
-                                    );
-                                    configuration
-                                        .get_mut(&Value::String("permissions".to_owned()))
-                                        .unwrap()
-                                        .as_mapping_mut()
-                                        .unwrap()
-                                }
-                                Value::Mapping(v) => v,
-                                _ => unreachable!(),
-                            };
-                            provide_default!(
-                                permissions,
-                                "IsThirdPartyPurchaseAllowed",
-                                default_model.permissions.is_third_party_purchase_allowed
-                            );
-                            provide_default!(
-                                permissions,
-                                "IsThirdPartyTeleportAllowed",
-                                default_model.permissions.is_third_party_teleport_allowed
-                            );
-                        }
-                        provide_default!(
-                            configuration,
-                            "universeAvatarType",
-                            default_model.universe_avatar_type
-                        );
-                        provide_default!(
-                            configuration,
-                            "universeAnimationType",
-                            default_model.universe_animation_type
-                        );
-                        provide_default!(
-                            configuration,
-                            "universeCollisionType",
-                            default_model.universe_collision_type
-                        );
-                        provide_default!(
-                            configuration,
-                            "universeAvatarMinScales",
-                            default_model.universe_avatar_min_scales
-                        );
-                        provide_default!(
-                            configuration,
-                            "universeAvatarMaxScales",
-                            default_model.universe_avatar_max_scales
-                        );
-                        provide_default!(
-                            configuration,
-                            "universeAvatarAssetOverrides",
-                            default_model.universe_avatar_asset_overrides
-                        );
-                        provide_default!(configuration, "isArchived", default_model.is_archived);
-                    }
-                }
-
                 // Resources format change: add missing place configuration defaults. Previously,
                 // the PlaceConfigurationModel allowed all options to be missing, but the new
                 // version requires that all options be set.
@@ -124,10 +124,12 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                         &format!("experience_{}", resource.id),
                         RobloxInputs::Experience(ExperienceInputs {
                             group_id: input_value!(resource, "groupId"),
+                            asset_id: None,
                         }),
                         RobloxOutputs::Experience(ExperienceOutputs {
                             asset_id: output_value!(resource, "assetId"),
                             start_place_id: output_value!(resource, "startPlaceId"),
+                            is_archived: false,
                         }),
                         &[],
                     )
@@ -138,7 +140,7 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                             resource,
                             "configuration"
                         )),
-                        RobloxOutputs::ExperienceConfiguration,
+                        RobloxOutputs::ExperienceConfiguration(Box::default()),
                         &[dependency!(ref_to_resource, resource, "experienceId")],
                     )
                     .into(),
@@ -153,8 +155,9 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                     .into(),
                     "experienceIcon" => RobloxResource::existing(
                         "experienceIcon_singleton",
-                        RobloxInputs::ExperienceIcon(FileInputs {
+                        RobloxInputs::ExperienceIcon(ExperienceIconInputs {
                             file_path: input_value!(resource, "filePath"),
+                            url: None,
                             file_hash: input_value!(resource, "fileHash"),
                         }),
                         RobloxOutputs::ExperienceIcon(AssetOutputs {
@@ -165,9 +168,11 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                     .into(),
                     "experienceThumbnail" => RobloxResource::existing(
                         &format!("experienceThumbnail_{}", resource.id),
-                        RobloxInputs::ExperienceThumbnail(FileInputs {
+                        RobloxInputs::ExperienceThumbnail(ExperienceThumbnailInputs {
                             file_path: input_value!(resource, "filePath"),
+                            url: None,
                             file_hash: input_value!(resource, "fileHash"),
+                            asset_id: None,
                         }),
                         RobloxOutputs::ExperienceThumbnail(AssetOutputs {
                             asset_id: output_value!(resource, "assetId"),
@@ -180,7 +185,9 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                         RobloxResource::existing(
                             &format!("experienceThumbnailOrder_{}", resource.id),
                             RobloxInputs::ExperienceThumbnailOrder,
-                            RobloxOutputs::ExperienceThumbnailOrder,
+                            RobloxOutputs::ExperienceThumbnailOrder(
+                                ExperienceThumbnailOrderOutputs::default(),
+                            ),
                             &thumbnails,
                         )
                         .add_dependency(dependency!(ref_to_resource, resource, "experienceId"))
@@ -191,6 +198,8 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                         &format!("place_{}", resource.id),
                         RobloxInputs::Place(PlaceInputs {
                             is_start: resource.id == "start",
+                            name: None,
+                            template_place_id: None,
                         }),
                         RobloxOutputs::Place(AssetOutputs {
                             asset_id: output_value!(resource, "assetId"),
@@ -200,12 +209,16 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                     .into(),
                     "placeFile" => RobloxResource::existing(
                         &format!("placeFile_{}", resource.id),
-                        RobloxInputs::PlaceFile(FileInputs {
-                            file_path: input_value!(resource, "filePath"),
-                            file_hash: input_value!(resource, "fileHash"),
+                        RobloxInputs::PlaceFile(PlaceFileInputs {
+                            file_path: Some(input_value!(resource, "filePath")),
+                            file_hash: Some(input_value!(resource, "fileHash")),
+                            version: None,
+                            file_format: None,
                         }),
                         RobloxOutputs::PlaceFile(PlaceFileOutputs {
                             version: output_value!(resource, "version"),
+                            place_id: None,
+                            file_hash: None,
                         }),
                         &[dependency!(ref_to_resource, resource, "assetId")],
                     )
@@ -213,7 +226,7 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                     "placeConfiguration" => RobloxResource::existing(
                         &format!("placeConfiguration_{}", resource.id),
                         RobloxInputs::PlaceConfiguration(input_value!(resource, "configuration")),
-                        RobloxOutputs::PlaceConfiguration,
+                        RobloxOutputs::PlaceConfiguration(PlaceConfigurationOutputs::default()),
                         &[dependency!(ref_to_resource, resource, "assetId")],
                     )
                     .into(),
@@ -237,6 +250,7 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                                 name: input_value!(resource, "name"),
                                 description: input_value!(resource, "description"),
                                 price: input_value!(resource, "price"),
+                                regional_prices: None,
                             }),
                             RobloxOutputs::Product(ProductOutputs {
                                 asset_id: output_value!(resource, "assetId"),
@@ -254,9 +268,10 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                     }
                     "developerProductIcon" => RobloxResource::existing(
                         &format!("productIcon_{}", resource.id),
-                        RobloxInputs::ProductIcon(FileInputs {
+                        RobloxInputs::ProductIcon(ProductIconInputs {
                             file_path: input_value!(resource, "filePath"),
                             file_hash: input_value!(resource, "fileHash"),
+                            asset_id: None,
                         }),
                         RobloxOutputs::ProductIcon(AssetOutputs {
                             asset_id: output_value!(resource, "assetId"),
@@ -316,8 +331,9 @@ impl From<ResourceStateV2> for ResourceStateV3 {
                             file_path: input_value!(resource, "filePath"),
                             file_hash: input_value!(resource, "fileHash"),
                         }),
-                        RobloxOutputs::BadgeIcon(AssetOutputs {
+                        RobloxOutputs::BadgeIcon(BadgeIconOutputs {
                             asset_id: output_value!(resource, "assetId"),
+                            file_hash: None,
                         }),
                         &[dependency!(ref_to_resource, resource, "badgeId")],
                     )